@@ -3,11 +3,12 @@ mod system_properties;
 
 use crate::extra;
 use crate::traits::*;
-use itertools::Itertools;
-use std::ffi::{CStr, CString};
 use std::fs;
+use std::net::IpAddr;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::Command;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
 use sysinfo_ffi::sysinfo;
 use system_properties::getprop;
 
@@ -22,155 +23,229 @@ impl From<std::num::ParseFloatError> for ReadoutError {
     }
 }
 
-pub struct AndroidBatteryReadout;
+pub struct AndroidBatteryReadout {
+    battery: Option<PathBuf>,
+}
 
 pub struct AndroidKernelReadout {
-    utsname: Option<libc::utsname>,
+    /// The result of calling `uname` when the readout was created, holding the
+    /// reason it failed otherwise.
+    utsname: Result<libc::utsname, String>,
 }
 
 pub struct AndroidGeneralReadout {
-    sysinfo: sysinfo,
+    sysinfo: SysinfoCache,
+    cpu_model_name: Mutex<Option<String>>,
+    cpu_jiffies: Option<(u64, u64)>,
 }
 
 pub struct AndroidMemoryReadout {
-    sysinfo: sysinfo,
+    sysinfo: SysinfoCache,
 }
 
 pub struct AndroidProductReadout;
 
 pub struct AndroidPackageReadout;
 
-impl BatteryReadout for AndroidBatteryReadout {
+pub struct AndroidGpuReadout;
+
+pub struct AndroidDiskReadout;
+
+pub struct AndroidNetworkReadout;
+
+/// Holds a single `sysinfo` snapshot so that reading several fields from the
+/// same readout only costs one `sysinfo(2)` system call.
+///
+/// The snapshot is taken when the readout is created and is **not** updated
+/// afterwards, which means values such as the uptime or the amount of free
+/// memory grow stale the longer a readout lives. Call `refresh()` on the readout
+/// to take a new snapshot.
+struct SysinfoCache(Mutex<Option<sysinfo>>);
+
+impl SysinfoCache {
     fn new() -> Self {
-        AndroidBatteryReadout
+        let cache = SysinfoCache(Mutex::new(None));
+        cache.refresh();
+        cache
     }
 
-    fn percentage(&self) -> Result<u8, ReadoutError> {
-        let bat_path = Path::new("/sys/class/power_supply/battery/capacity");
-        let percentage_text = extra::pop_newline(fs::read_to_string(bat_path)?);
-        let percentage_parsed = percentage_text.parse::<u8>();
-
-        match percentage_parsed {
-            Ok(p) => Ok(p),
-            Err(e) => Err(ReadoutError::Other(format!(
-                "Could not parse the value '{}' of {} into a \
-            digit: {:?}",
-                percentage_text,
-                bat_path.to_str().unwrap_or_default(),
-                e
-            ))),
+    fn snapshot() -> Option<sysinfo> {
+        let mut info = sysinfo::new();
+        let info_ptr: *mut sysinfo = &mut info;
+        let ret = unsafe { sysinfo(info_ptr) };
+        if ret != -1 {
+            Some(info)
+        } else {
+            None
         }
     }
 
-    fn status(&self) -> Result<BatteryState, ReadoutError> {
-        let bat_path = Path::new("/sys/class/power_supply/battery/status");
-
-        let status_text = extra::pop_newline(fs::read_to_string(bat_path)?).to_lowercase();
-        match &status_text[..] {
-            "charging" => Ok(BatteryState::Charging),
-            "discharging" | "full" => Ok(BatteryState::Discharging),
-            s => Err(ReadoutError::Other(format!(
-                "Got unexpected value '{}' from {}.",
-                s,
-                bat_path.to_str().unwrap_or_default()
-            ))),
+    fn lock(&self) -> MutexGuard<'_, Option<sysinfo>> {
+        // A snapshot is replaced as a whole, so a poisoned one is still consistent.
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn refresh(&self) {
+        *self.lock() = SysinfoCache::snapshot();
+    }
+
+    /// Returns the cached snapshot, retrying the system call if the previous
+    /// attempt failed.
+    fn get(&self) -> Result<sysinfo, ReadoutError> {
+        let mut cached = self.lock();
+        if cached.is_none() {
+            *cached = SysinfoCache::snapshot();
         }
+
+        cached.ok_or_else(|| ReadoutError::Other("Failed to get system statistics".to_string()))
     }
 }
 
-impl KernelReadout for AndroidKernelReadout {
+impl BatteryReadout for AndroidBatteryReadout {
     fn new() -> Self {
-        let mut __utsname: libc::utsname = unsafe { std::mem::zeroed() };
-        let utsname: Option<libc::utsname>;
-        if unsafe { libc::uname(&mut __utsname) } == -1 {
-            utsname = None
-        } else {
-            utsname = Some(__utsname)
-        }
-        AndroidKernelReadout { utsname }
+        // Most devices name their battery node "battery", but e.g. "BAT0" or
+        // "max170xx_battery" are used as well.
+        let battery = crate::shared::battery_dirs()
+            .into_iter()
+            .next()
+            .or_else(|| {
+                let battery = PathBuf::from("/sys/class/power_supply/battery");
+                if battery.is_dir() {
+                    Some(battery)
+                } else {
+                    None
+                }
+            });
+
+        AndroidBatteryReadout { battery }
     }
 
-    fn os_release(&self) -> Result<String, ReadoutError> {
-        if let Some(utsname) = self.utsname {
-            return Ok(unsafe { CStr::from_ptr(utsname.release.as_ptr()) }
-                .to_str()
-                .unwrap()
-                .to_owned());
-        } else {
-            Err(ReadoutError::Other(String::from(
-                "Failed to get os_release",
-            )))
-        }
+    fn percentage(&self) -> Result<u8, ReadoutError> {
+        crate::shared::combined_battery_percentage(&self.batteries()?)
     }
 
-    fn os_type(&self) -> Result<String, ReadoutError> {
-        if let Some(utsname) = self.utsname {
-            return Ok(unsafe { CStr::from_ptr(utsname.sysname.as_ptr()) }
-                .to_str()
-                .unwrap()
-                .to_owned());
-        } else {
-            Err(ReadoutError::Other(String::from("Failed to get os_type")))
-        }
+    fn percentage_all(&self) -> Result<Vec<(String, u8)>, ReadoutError> {
+        Ok(crate::shared::battery_percentages())
     }
-}
 
-impl GeneralReadout for AndroidGeneralReadout {
-    fn new() -> Self {
-        AndroidGeneralReadout {
-            sysinfo: sysinfo::new(),
-        }
+    fn status(&self) -> Result<BatteryState, ReadoutError> {
+        crate::shared::combined_battery_state(&self.batteries()?)
     }
 
-    fn machine(&self) -> Result<String, ReadoutError> {
-        let product_readout = AndroidProductReadout::new();
+    fn health(&self) -> Result<u8, ReadoutError> {
+        crate::shared::battery_health(self.battery_dir()?)
+    }
 
-        let vendor = product_readout.vendor()?;
-        let family = product_readout.family()?;
-        let product = product_readout.product()?;
+    fn technology(&self) -> Result<String, ReadoutError> {
+        crate::shared::battery_technology(self.battery_dir()?)
+    }
 
-        let product = format!("{} {} ({})", vendor, family, product);
-        let new_product: Vec<_> = product.split_whitespace().into_iter().unique().collect();
+    fn capacity_design(&self) -> Result<u64, ReadoutError> {
+        crate::shared::battery_capacity_design(self.battery_dir()?)
+    }
+
+    fn time_remaining(&self) -> Result<u32, ReadoutError> {
+        let battery = self.battery_dir()?;
+        crate::shared::battery_time_remaining(battery, crate::shared::battery_state(battery)?)
+    }
 
-        if product.is_empty() || product.len() <= 15 {
-            return Ok(new_product.into_iter().join(" "));
+    fn power_draw(&self) -> Result<f32, ReadoutError> {
+        let battery = self.battery_dir()?;
+        crate::shared::battery_power_draw(battery, crate::shared::battery_state(battery)?)
+    }
+
+    fn voltage(&self) -> Result<f32, ReadoutError> {
+        crate::shared::battery_voltage(self.battery_dir()?)
+    }
+
+    fn temperature(&self) -> Result<f32, ReadoutError> {
+        crate::shared::battery_temperature(self.battery_dir()?)
+    }
+}
+
+impl AndroidBatteryReadout {
+    /// Returns the battery's directory in `/sys/class/power_supply`, which is looked up
+    /// once when the readout is created.
+    fn battery_dir(&self) -> Result<&Path, ReadoutError> {
+        self.battery
+            .as_deref()
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    /// Returns the directories of every battery, or just `battery_dir()` if no supply
+    /// reports itself as a battery.
+    fn batteries(&self) -> Result<Vec<PathBuf>, ReadoutError> {
+        let batteries = crate::shared::battery_dirs();
+        if batteries.is_empty() {
+            return Ok(vec![self.battery_dir()?.to_path_buf()]);
         }
 
-        Ok(product)
+        Ok(batteries)
     }
+}
 
-    fn local_ip(&self, interface: Option<String>) -> Result<String, ReadoutError> {
-        crate::shared::local_ip(interface)
+impl KernelReadout for AndroidKernelReadout {
+    fn new() -> Self {
+        let mut utsname: libc::utsname = unsafe { std::mem::zeroed() };
+        let utsname = if unsafe { libc::uname(&mut utsname) } == -1 {
+            // errno has to be read right away, before another call overwrites it.
+            Err(std::io::Error::last_os_error().to_string())
+        } else {
+            Ok(utsname)
+        };
+
+        AndroidKernelReadout { utsname }
     }
 
-    fn username(&self) -> Result<String, ReadoutError> {
-        crate::shared::username()
+    fn os_release(&self) -> Result<String, ReadoutError> {
+        crate::shared::utsname_field(&self.utsname("os_release")?.release)
+    }
+
+    fn os_type(&self) -> Result<String, ReadoutError> {
+        crate::shared::utsname_field(&self.utsname("os_type")?.sysname)
+    }
+
+    fn architecture(&self) -> Result<String, ReadoutError> {
+        crate::shared::utsname_field(&self.utsname("architecture")?.machine)
+    }
+
+    fn libc_version(&self) -> Result<String, ReadoutError> {
+        Ok(String::from("bionic"))
     }
 
     fn hostname(&self) -> Result<String, ReadoutError> {
-        let __name: *mut std::os::raw::c_char = CString::new("").unwrap().into_raw();
-        let __len: usize = libc::_SC_HOST_NAME_MAX as usize;
-        let ret = unsafe { libc::gethostname(__name, __len) };
-        if ret == -1 {
-            Err(ReadoutError::Other(String::from("Failed to get hostname")))
-        } else {
-            Ok(unsafe { CStr::from_ptr(__name).to_string_lossy().into_owned() })
-        }
+        crate::shared::hostname()
     }
+}
 
-    fn shell(&self, format: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
-        if let Some(shell) = std::env::var_os("SHELL") {
-            if let Some(relative) = PathBuf::from(shell).file_name() {
-                if let Some(str) = relative.to_str() {
-                    return Ok(str.to_owned());
-                }
-            }
-        }
+impl AndroidKernelReadout {
+    /// Returns whether `uname` succeeded when the readout was created. If it didn't,
+    /// every getter backed by it fails with the reason `uname` gave.
+    pub fn uname_available(&self) -> bool {
+        self.utsname.is_ok()
+    }
 
-        return crate::shared::shell(format, kind);
+    /// Returns the `uname` result, or an error explaining why `metric` is unavailable.
+    fn utsname(&self, metric: &str) -> Result<&libc::utsname, ReadoutError> {
+        self.utsname.as_ref().map_err(|reason| {
+            ReadoutError::Other(format!(
+                "Failed to get {}: uname failed: {}",
+                metric, reason
+            ))
+        })
     }
+}
 
-    fn cpu_model_name(&self) -> Result<String, ReadoutError> {
+impl AndroidGeneralReadout {
+    /// Re-reads the system statistics that `uptime()` and `cpu_usage()` report,
+    /// which are otherwise cached from the moment this readout was created.
+    pub fn refresh(&self) {
+        self.sysinfo.refresh();
+    }
+
+    /// Returns the SoC or processor name found in `/proc/cpuinfo`, preferring the
+    /// `Hardware` line, which ARM kernels use for the SoC, over `model name` and `Processor`.
+    fn read_cpu_model_name() -> Result<String, ReadoutError> {
         use std::io::{BufRead, BufReader};
         let file = fs::File::open("/proc/cpuinfo");
         let mut model: Option<String> = None;
@@ -205,16 +280,167 @@ impl GeneralReadout for AndroidGeneralReadout {
                 }
             }
         }
-        match (hardware, model, processor) {
-            (Some(hardware), _, _) => Ok(hardware),
-            (_, Some(model), _) => Ok(model),
-            (_, _, Some(processor)) => Ok(processor),
-            (_, _, _) => Err(ReadoutError::Other(String::from(
-                "Failed to get processor model name",
+        let name = match (hardware, model, processor) {
+            (Some(hardware), _, _) => hardware,
+            (_, Some(model), _) => model,
+            (_, _, Some(processor)) => processor,
+            (_, _, _) => {
+                return Err(ReadoutError::Other(String::from(
+                    "Failed to get processor model name",
+                )))
+            }
+        };
+
+        Ok(name.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+}
+
+impl GeneralReadout for AndroidGeneralReadout {
+    fn new() -> Self {
+        AndroidGeneralReadout {
+            sysinfo: SysinfoCache::new(),
+            cpu_model_name: Mutex::new(None),
+            cpu_jiffies: None,
+        }
+    }
+
+    fn machine(&self) -> Result<String, ReadoutError> {
+        let product_readout = AndroidProductReadout::new();
+
+        let vendor = product_readout.vendor()?;
+        let family = product_readout.family()?;
+        let product = product_readout.product()?;
+
+        Ok(extra::normalize_machine_name(&vendor, &family, &product))
+    }
+
+    fn host_model(&self) -> Result<String, ReadoutError> {
+        self.machine()
+    }
+
+    fn local_ip(&self, interface: Option<String>) -> Result<String, ReadoutError> {
+        crate::shared::local_ip(interface)
+    }
+
+    fn local_ips(&self, interface: Option<String>) -> Result<Vec<IpAddr>, ReadoutError> {
+        crate::shared::local_ips(interface)
+    }
+
+    fn resolution(&self) -> Result<Vec<String>, ReadoutError> {
+        let resolutions = crate::shared::drm_resolutions();
+        if !resolutions.is_empty() {
+            return Ok(resolutions);
+        }
+
+        // DRM is rarely readable by apps, so fall back to the window manager, which
+        // prints e.g. "Physical size: 1080x2400" and, if set, "Override size: 720x1600".
+        let output = crate::shared::run_with_timeout(
+            Command::new("wm").arg("size"),
+            crate::shared::COMMAND_TIMEOUT,
+        )
+        .ok_or(ReadoutError::MetricNotAvailable)?;
+
+        let sizes = String::from_utf8_lossy(&output.stdout);
+        let size_of = |prefix: &str| {
+            sizes
+                .lines()
+                .find_map(|line| line.strip_prefix(prefix))
+                .map(|size| size.trim().to_string())
+        };
+
+        match size_of("Override size:").or_else(|| size_of("Physical size:")) {
+            Some(size) => Ok(vec![size]),
+            None => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    fn username(&self) -> Result<String, ReadoutError> {
+        crate::shared::username()
+    }
+
+    fn desktop_environment(&self) -> Result<String, ReadoutError> {
+        match getprop("ro.build.version.release") {
+            Some(version) if !version.is_empty() => Ok(format!("Android {}", version)),
+            _ => Ok(String::from("Android")),
+        }
+    }
+
+    fn distribution(&self) -> Result<String, ReadoutError> {
+        match getprop("ro.build.version.release") {
+            Some(version) if !version.is_empty() => Ok(format!("Android {}", version)),
+            _ => Err(ReadoutError::Other(String::from(
+                "Could not obtain the Android version.",
             ))),
         }
     }
 
+    fn os_logo_id(&self) -> Result<String, ReadoutError> {
+        Ok(String::from("android"))
+    }
+
+    fn locale(&self) -> Result<String, ReadoutError> {
+        // The environment is usually empty for apps, so fall back to the system locale.
+        crate::shared::locale().or_else(|e| {
+            ["persist.sys.locale", "ro.product.locale"]
+                .iter()
+                .filter_map(getprop)
+                .find(|locale| !locale.is_empty())
+                .ok_or(e)
+        })
+    }
+
+    fn timezone(&self) -> Result<String, ReadoutError> {
+        match getprop("persist.sys.timezone") {
+            Some(timezone) if !timezone.is_empty() => Ok(timezone),
+            _ => Err(ReadoutError::Other(String::from("No timezone is set."))),
+        }
+    }
+
+    fn hostname(&self) -> Result<String, ReadoutError> {
+        crate::shared::hostname()
+    }
+
+    fn terminal(&self) -> Result<String, ReadoutError> {
+        crate::shared::terminal()
+    }
+
+    fn shell(&self, format: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
+        // Only the current shell is taken from the environment, the default
+        // shell is always looked up in the user database.
+        if let ShellKind::Current = kind {
+            if let Some(shell) = std::env::var_os("SHELL") {
+                if let Some(shell) = crate::shared::format_shell_path(Path::new(&shell), &format) {
+                    return Ok(shell);
+                }
+            }
+        }
+
+        crate::shared::shell(format, kind)
+    }
+
+    fn cpu_model_name(&self) -> Result<String, ReadoutError> {
+        // The model name can't change, so /proc/cpuinfo is only read until it succeeds.
+        let mut cached = self.cpu_model_name.lock().map_err(|_| {
+            ReadoutError::Other(String::from("The CPU model name cache is poisoned."))
+        })?;
+
+        if let Some(model_name) = cached.as_ref() {
+            return Ok(model_name.clone());
+        }
+
+        let model_name = AndroidGeneralReadout::read_cpu_model_name()?;
+        *cached = Some(model_name.clone());
+        Ok(model_name)
+    }
+
+    fn cpu_features(&self) -> Result<Vec<String>, ReadoutError> {
+        crate::shared::cpu_features()
+    }
+
+    fn cpu_vendor(&self) -> Result<String, ReadoutError> {
+        crate::shared::cpu_vendor()
+    }
+
     fn cpu_physical_cores(&self) -> Result<usize, ReadoutError> {
         crate::shared::cpu_physical_cores()
     }
@@ -223,101 +449,202 @@ impl GeneralReadout for AndroidGeneralReadout {
         crate::shared::cpu_cores()
     }
 
-    fn cpu_usage(&self) -> Result<usize, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            let f_load = 1f64 / (1 << libc::SI_LOAD_SHIFT) as f64;
-            let cpu_usage = info.loads[0] as f64 * f_load;
-            let cpu_usage_u = (cpu_usage / num_cpus::get() as f64 * 100.0).round() as usize;
-            if cpu_usage_u != 0 {
-                return Ok(cpu_usage_u as usize);
+    fn cpu_cores_by_cluster(&self) -> Result<Vec<(u64, usize)>, ReadoutError> {
+        crate::shared::cpu_cores_by_cluster()
+    }
+
+    fn cpu_temperature(&self) -> Result<f32, ReadoutError> {
+        crate::shared::cpu_temperature()
+    }
+
+    fn virtualization(&self) -> Result<String, ReadoutError> {
+        crate::shared::virtualization()
+    }
+
+    fn gpu_usage(&self) -> Result<usize, ReadoutError> {
+        // Adreno GPUs report the busy and total cycles of the last sampling window.
+        if let Ok(gpubusy) =
+            crate::shared::read_sysfs_string(Path::new("/sys/class/kgsl/kgsl-3d0/gpubusy"))
+        {
+            let cycles: Vec<u64> = gpubusy
+                .split_whitespace()
+                .filter_map(|cycles| cycles.parse().ok())
+                .collect();
+
+            if let [busy, total] = cycles[..] {
+                if total == 0 {
+                    return Ok(0);
+                }
+
+                return Ok((busy * 100 / total).min(100) as usize);
             }
-            Err(ReadoutError::Other("Processor usage is null.".to_string()))
-        } else {
-            Err(ReadoutError::Other(
-                "Failed to get system statistics".to_string(),
-            ))
         }
+
+        // Mali GPUs report their utilisation in percent.
+        crate::shared::read_sysfs_u64(Path::new("/sys/class/misc/mali0/device/utilization"))
+            .map(|usage| usage.min(100) as usize)
+            .map_err(|_| ReadoutError::MetricNotAvailable)
+    }
+
+    fn accent_color(&self) -> Result<(u8, u8, u8), ReadoutError> {
+        // Material You keeps the wallpaper-derived or user-picked palette in a secure
+        // setting, which is only readable from a shell with enough privileges.
+        let output = crate::shared::run_with_timeout(
+            Command::new("settings").args([
+                "get",
+                "secure",
+                "theme_customization_overlay_packages",
+            ]),
+            crate::shared::COMMAND_TIMEOUT,
+        )
+        .ok_or(ReadoutError::MetricNotAvailable)?;
+
+        crate::shared::parse_android_accent_color(&String::from_utf8_lossy(&output.stdout))
+            .ok_or(ReadoutError::MetricNotAvailable)
     }
 
-    fn uptime(&self) -> Result<usize, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            Ok(info.uptime as usize)
-        } else {
-            Err(ReadoutError::Other(
-                "Failed to get system statistics".to_string(),
-            ))
+    fn cpu_frequency(&self) -> Result<u64, ReadoutError> {
+        crate::shared::cpu_frequency()
+    }
+
+    fn cpu_usage_per_core_interval(&self, interval: Duration) -> Result<Vec<usize>, ReadoutError> {
+        crate::shared::cpu_usage_per_core(interval)
+    }
+
+    fn load_average(&self) -> Result<(f64, f64, f64), ReadoutError> {
+        let info = self.sysinfo.get()?;
+        let f_load = 1f64 / (1 << libc::SI_LOAD_SHIFT) as f64;
+
+        Ok((
+            info.loads[0] as f64 * f_load,
+            info.loads[1] as f64 * f_load,
+            info.loads[2] as f64 * f_load,
+        ))
+    }
+
+    fn cpu_usage_interval(&self, interval: Duration) -> Result<usize, ReadoutError> {
+        if let Ok(usage) = crate::shared::cpu_usage(interval) {
+            return Ok(usage);
         }
+
+        // Apps are denied access to /proc/stat since Android 8, in which case
+        // the usage can only be estimated from the load average.
+        let info = self.sysinfo.get()?;
+        let f_load = 1f64 / (1 << libc::SI_LOAD_SHIFT) as f64;
+        let cpu_usage = info.loads[0] as f64 * f_load;
+        let cpu_usage_u = (cpu_usage / num_cpus::get() as f64 * 100.0).round() as usize;
+        Ok(cpu_usage_u.min(100))
+    }
+
+    fn cpu_usage_sampled(&mut self) -> Result<usize, ReadoutError> {
+        let current = crate::shared::total_jiffies()?;
+
+        match self.cpu_jiffies.replace(current) {
+            Some(previous) => Ok(crate::shared::jiffies_usage(previous, current)),
+            None => self.cpu_usage(),
+        }
+    }
+
+    fn uptime(&self) -> Result<usize, ReadoutError> {
+        Ok(self.sysinfo.get()?.uptime as usize)
+    }
+
+    fn boot_time(&self) -> Result<u64, ReadoutError> {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let uptime = self.sysinfo.get()?.uptime as u64;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| ReadoutError::Other(format!("The system clock is invalid: {}", e)))?;
+
+        Ok(now.as_secs().saturating_sub(uptime))
+    }
+
+    fn process_count(&self) -> Result<usize, ReadoutError> {
+        Ok(self.sysinfo.get()?.procs as usize)
+    }
+
+    fn loaded_modules(&self) -> Result<Vec<String>, ReadoutError> {
+        crate::shared::loaded_modules()
+    }
+}
+
+impl AndroidMemoryReadout {
+    /// Re-reads the system statistics that `total()`, `free()` and `buffers()`
+    /// report, which are otherwise cached from the moment this readout was
+    /// created.
+    pub fn refresh(&self) {
+        self.sysinfo.refresh();
     }
 }
 
 impl MemoryReadout for AndroidMemoryReadout {
     fn new() -> Self {
         AndroidMemoryReadout {
-            sysinfo: sysinfo::new(),
+            sysinfo: SysinfoCache::new(),
         }
     }
 
     fn total(&self) -> Result<u64, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            Ok(info.totalram * info.mem_unit as u64 / 1024)
-        } else {
-            Err(ReadoutError::Other(
-                "Failed to get system statistics".to_string(),
-            ))
-        }
+        let info = self.sysinfo.get()?;
+        Ok(info.totalram * info.mem_unit as u64 / 1024)
     }
 
     fn free(&self) -> Result<u64, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            Ok(info.freeram * info.mem_unit as u64 / 1024)
-        } else {
-            Err(ReadoutError::Other(
-                "Failed to get system statistics".to_string(),
-            ))
-        }
+        let info = self.sysinfo.get()?;
+        Ok(info.freeram * info.mem_unit as u64 / 1024)
     }
 
     fn buffers(&self) -> Result<u64, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            Ok(info.bufferram * info.mem_unit as u64 / 1024)
-        } else {
-            Err(ReadoutError::Other(
-                "Failed to get system statistics".to_string(),
-            ))
-        }
+        let info = self.sysinfo.get()?;
+        Ok(info.bufferram * info.mem_unit as u64 / 1024)
+    }
+
+    fn available(&self) -> Result<u64, ReadoutError> {
+        crate::shared::get_meminfo_value("MemAvailable")
     }
 
     fn cached(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("Cached"))
+        crate::shared::get_meminfo_value("Cached")
     }
 
     fn reclaimable(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("SReclaimable"))
+        crate::shared::get_meminfo_value("SReclaimable")
     }
 
     fn used(&self) -> Result<u64, ReadoutError> {
-        let total = self.total().unwrap();
-        let free = self.free().unwrap();
-        let cached = self.cached().unwrap();
-        let reclaimable = self.reclaimable().unwrap();
-        let buffers = self.buffers().unwrap();
+        let total = self.total()?;
+        let free = self.free()?;
+        let cached = self.cached()?;
+        let reclaimable = self.reclaimable()?;
+        let buffers = self.buffers()?;
 
-        Ok(total - free - cached - reclaimable - buffers)
+        Ok(crate::shared::used_memory(
+            total,
+            free,
+            cached,
+            reclaimable,
+            buffers,
+        ))
+    }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        crate::shared::get_meminfo_value("SwapTotal")
+    }
+
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let total = self.swap_total()?;
+        let free = crate::shared::get_meminfo_value("SwapFree")?;
+
+        Ok(total.saturating_sub(free))
+    }
+
+    fn zram_used(&self) -> Result<u64, ReadoutError> {
+        Ok(crate::shared::zram_usage(Path::new("/sys/block"))?.0)
+    }
+
+    fn zram_total(&self) -> Result<u64, ReadoutError> {
+        Ok(crate::shared::zram_usage(Path::new("/sys/block"))?.1)
     }
 }
 
@@ -365,6 +692,47 @@ impl ProductReadout for AndroidProductReadout {
         // ro.product.vendor.device
         // Same in all cases ( needs more testing in other devices )
     }
+
+    fn serial_number(&self) -> Result<String, ReadoutError> {
+        // Since Android 8, ro.serialno is only readable by privileged apps,
+        // other callers get an empty value or "unknown".
+        match getprop("ro.serialno") {
+            Some(serial) if !serial.is_empty() && serial != "unknown" => Ok(serial),
+            _ => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
+
+    fn form_factor(&self) -> Result<String, ReadoutError> {
+        // ro.build.characteristics is a comma-separated list, e.g. "nosdcard,tablet",
+        // whose most specific token decides the form factor. Phones usually report "default".
+        let characteristics = getprop("ro.build.characteristics").unwrap_or_default();
+        let tokens: Vec<&str> = characteristics.split(',').map(str::trim).collect();
+
+        let form_factor = ["watch", "tv", "automotive", "tablet"]
+            .iter()
+            .find(|form_factor| tokens.contains(form_factor))
+            .unwrap_or(&"phone");
+
+        Ok(form_factor.to_string())
+    }
+
+    fn build_fingerprint(&self) -> Result<String, ReadoutError> {
+        match getprop("ro.build.fingerprint") {
+            Some(fingerprint) if !fingerprint.is_empty() => Ok(fingerprint),
+            _ => Err(ReadoutError::Other(String::from(
+                "Could not obtain the build fingerprint.",
+            ))),
+        }
+    }
+
+    fn build_id(&self) -> Result<String, ReadoutError> {
+        match getprop("ro.build.id") {
+            Some(id) if !id.is_empty() => Ok(id),
+            _ => Err(ReadoutError::Other(String::from(
+                "Could not obtain the build ID.",
+            ))),
+        }
+    }
 }
 
 impl PackageReadout for AndroidPackageReadout {
@@ -372,27 +740,36 @@ impl PackageReadout for AndroidPackageReadout {
         AndroidPackageReadout
     }
 
-    /// Supports: pm, dpkg, cargo
+    /// Supports: pm, dpkg, cargo, flatpak, pip, nix
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
-        let mut packages = Vec::new();
         // Since the target is Android we can assume that pm is available
-        if let Some(c) = AndroidPackageReadout::count_pm() {
-            packages.push((PackageManager::Android, c));
-        }
+        let mut counters: Vec<crate::shared::PackageCounter> =
+            vec![(PackageManager::Android, AndroidPackageReadout::count_pm)];
 
         if extra::which("dpkg") {
-            if let Some(c) = AndroidPackageReadout::count_dpkg() {
-                packages.push((PackageManager::Dpkg, c));
-            }
+            counters.push((PackageManager::Dpkg, AndroidPackageReadout::count_dpkg));
         }
 
         if extra::which("cargo") {
-            if let Some(c) = AndroidPackageReadout::count_cargo() {
-                packages.push((PackageManager::Cargo, c));
-            }
+            counters.push((PackageManager::Cargo, AndroidPackageReadout::count_cargo));
         }
 
-        packages
+        if extra::which("flatpak") {
+            counters.push((
+                PackageManager::Flatpak,
+                AndroidPackageReadout::count_flatpak,
+            ));
+        }
+
+        if extra::which("pip") || extra::which("python3") {
+            counters.push((PackageManager::Pip, AndroidPackageReadout::count_pip));
+        }
+
+        if Path::new("/nix").exists() {
+            counters.push((PackageManager::Nix, AndroidPackageReadout::count_nix));
+        }
+
+        crate::shared::count_packages(counters)
     }
 }
 
@@ -400,16 +777,16 @@ impl AndroidPackageReadout {
     /// Returns the number of installed apps for the system
     /// Includes all apps ( user + system )
     fn count_pm() -> Option<usize> {
-        let pm_output = Command::new("pm")
-            .args(&["list", "packages"])
-            .stdout(Stdio::piped())
-            .output()
-            .unwrap();
-
-        extra::count_lines(
-            String::from_utf8(pm_output.stdout)
-                .expect("ERROR: \"pm list packages\" output was not valid UTF-8"),
-        )
+        let pm_output = crate::shared::run_with_timeout(
+            Command::new("pm").args(["list", "packages"]),
+            crate::shared::COMMAND_TIMEOUT,
+        )?;
+
+        if !pm_output.status.success() {
+            return None;
+        }
+
+        extra::count_lines(String::from_utf8(pm_output.stdout).ok()?)
     }
     /// Return the number of installed packages for systems
     /// that have `dpkg` installed.
@@ -447,4 +824,98 @@ impl AndroidPackageReadout {
     fn count_cargo() -> Option<usize> {
         crate::shared::count_cargo()
     }
+
+    /// Returns the number of installed packages for systems
+    /// that have `flatpak` installed.
+    fn count_flatpak() -> Option<usize> {
+        crate::shared::count_flatpak()
+    }
+
+    /// Returns the number of installed packages for systems
+    /// that have `pip` installed.
+    fn count_pip() -> Option<usize> {
+        crate::shared::count_pip()
+    }
+
+    /// Returns the number of installed packages for systems
+    /// that have `nix` installed.
+    fn count_nix() -> Option<usize> {
+        crate::shared::count_nix()
+    }
+}
+
+impl GpuReadout for AndroidGpuReadout {
+    fn new() -> Self {
+        AndroidGpuReadout
+    }
+
+    fn model(&self) -> Result<Vec<String>, ReadoutError> {
+        // The name of the vendor-provided GLES implementation,
+        // e.g. adreno or mali, is a good hint as to which GPU is in use.
+        if let Some(egl) = getprop("ro.hardware.egl") {
+            let gpu = match egl.to_lowercase().as_str() {
+                "" => None,
+                "adreno" => Some(String::from("Adreno")),
+                "mali" => Some(String::from("Mali")),
+                "powervr" => Some(String::from("PowerVR")),
+                _ => Some(extra::ucfirst(egl)),
+            };
+
+            if let Some(gpu) = gpu {
+                return Ok(vec![gpu]);
+            }
+        }
+
+        if let Ok(cpuinfo) = fs::read_to_string("/proc/cpuinfo") {
+            for hint in ["Adreno", "Mali"].iter() {
+                if cpuinfo.contains(hint) {
+                    return Ok(vec![hint.to_string()]);
+                }
+            }
+        }
+
+        Err(ReadoutError::Other(String::from(
+            "Could not detect the GPU of this device.",
+        )))
+    }
+}
+
+// The statvfs fields are not 64 bits wide on every architecture.
+#[allow(clippy::unnecessary_cast)]
+impl DiskReadout for AndroidDiskReadout {
+    fn new() -> Self {
+        AndroidDiskReadout
+    }
+
+    fn total(&self, path: &Path) -> Result<u64, ReadoutError> {
+        let stats = crate::shared::statvfs(path)?;
+        Ok(stats.f_blocks as u64 * stats.f_frsize as u64)
+    }
+
+    fn free(&self, path: &Path) -> Result<u64, ReadoutError> {
+        let stats = crate::shared::statvfs(path)?;
+        Ok(stats.f_bavail as u64 * stats.f_frsize as u64)
+    }
+}
+
+impl NetworkReadout for AndroidNetworkReadout {
+    fn new() -> Self {
+        AndroidNetworkReadout
+    }
+
+    fn tx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::network_statistic(interface, "tx_bytes")
+    }
+
+    fn rx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::network_statistic(interface, "rx_bytes")
+    }
+
+    fn mac_address(&self, interface: Option<String>) -> Result<String, ReadoutError> {
+        crate::shared::mac_address(interface)
+    }
+
+    fn link_speed(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::link_speed(interface)
+    }
 }
@@ -0,0 +1,59 @@
+//! Constructors that return the readouts of the current platform as trait objects.
+//!
+//! This lets downstream crates write platform-agnostic code, _e.g._ store the readouts
+//! behind a `Box<dyn GeneralReadout>` or substitute their own implementations in tests,
+//! without having to name the concrete types.
+//!
+//! ```
+//! use libmacchina::boxed;
+//! use libmacchina::traits::GeneralReadout;
+//!
+//! let general: Box<dyn GeneralReadout> = boxed::general();
+//! println!("{:?}", general.uptime().ok());
+//! ```
+use crate::traits;
+
+/// Returns the battery readout of the current platform.
+pub fn battery() -> Box<dyn traits::BatteryReadout> {
+    Box::new(<crate::BatteryReadout as traits::BatteryReadout>::new())
+}
+
+/// Returns the kernel readout of the current platform.
+pub fn kernel() -> Box<dyn traits::KernelReadout> {
+    Box::new(<crate::KernelReadout as traits::KernelReadout>::new())
+}
+
+/// Returns the memory readout of the current platform.
+pub fn memory() -> Box<dyn traits::MemoryReadout> {
+    Box::new(<crate::MemoryReadout as traits::MemoryReadout>::new())
+}
+
+/// Returns the general readout of the current platform.
+pub fn general() -> Box<dyn traits::GeneralReadout> {
+    Box::new(<crate::GeneralReadout as traits::GeneralReadout>::new())
+}
+
+/// Returns the product readout of the current platform.
+pub fn product() -> Box<dyn traits::ProductReadout> {
+    Box::new(<crate::ProductReadout as traits::ProductReadout>::new())
+}
+
+/// Returns the package readout of the current platform.
+pub fn packages() -> Box<dyn traits::PackageReadout> {
+    Box::new(<crate::PackageReadout as traits::PackageReadout>::new())
+}
+
+/// Returns the GPU readout of the current platform.
+pub fn gpu() -> Box<dyn traits::GpuReadout> {
+    Box::new(<crate::GpuReadout as traits::GpuReadout>::new())
+}
+
+/// Returns the disk readout of the current platform.
+pub fn disk() -> Box<dyn traits::DiskReadout> {
+    Box::new(<crate::DiskReadout as traits::DiskReadout>::new())
+}
+
+/// Returns the network readout of the current platform.
+pub fn network() -> Box<dyn traits::NetworkReadout> {
+    Box::new(<crate::NetworkReadout as traits::NetworkReadout>::new())
+}
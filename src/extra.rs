@@ -7,10 +7,12 @@ use std::io::{self, BufRead};
 use std::path::{Path, PathBuf};
 
 /**
-This function pops `\n` from the end of a given `String` if it is found.
+This function strips every trailing newline, carriage return and other
+whitespace from the end of a given `String`.
 
 This can come in handy when reading the contents of a file that might
-contain a newline control character at the end of the line.
+contain a newline control character at the end of the line, or the output
+of a command that ends its lines with `\r\n`.
 
 Files of this kind are very common on GNU/Linux systems.
 
@@ -30,9 +32,7 @@ where
     T: std::string::ToString,
 {
     let mut string = string.to_string();
-    if string.ends_with('\n') {
-        string.pop();
-    }
+    string.truncate(string.trim_end().len());
 
     string
 }
@@ -78,6 +78,7 @@ This can be used to check if a particular program exists before running a comman
 that could return an error in case the program is not installed.
 
 - Returns `true` if a given program is in __PATH__, and `false` if it isn't.
+- Only executable files are considered, so a same-named directory or data file is not a match.
 
 # Example
 ```
@@ -94,32 +95,82 @@ pub fn which<P>(program_name: P) -> bool
 where
     P: AsRef<Path>,
 {
-    let exists = env::var_os("PATH").and_then(|paths| {
-        env::split_paths(&paths).find_map(|dir| {
-            let full_path = dir.join(&program_name);
-            if full_path.exists() {
-                Some(full_path)
-            } else {
-                None
-            }
-        })
-    });
-
-    exists.is_some()
+    match env::var_os("PATH") {
+        Some(paths) => executables_in(&paths, program_name.as_ref())
+            .next()
+            .is_some(),
+        None => false,
+    }
 }
 
-// Returns the number of newlines in a buffer
-pub fn count_lines<T>(buffer: T) -> Option<usize>
+/**
+Search all directories in __PATH__ for a program and return every match, in __PATH__ order.
+
+This is useful for tools that are commonly installed more than once, _e.g._ a system
+and a user-local _python3_. As with `which`, only executable files are returned.
+
+# Example
+```
+use libmacchina::extra::which_all;
+
+for python in which_all("python3") {
+    println!("Found python3 at {}", python.display());
+}
+```
+*/
+pub fn which_all<P>(program_name: P) -> Vec<PathBuf>
 where
-    T: std::string::ToString,
+    P: AsRef<Path>,
 {
-    let buf = buffer.to_string().trim().to_owned();
+    match env::var_os("PATH") {
+        Some(paths) => executables_in(&paths, program_name.as_ref()).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Returns the executables called `program_name` in the directories of `paths`,
+/// which is formatted like __PATH__.
+fn executables_in<'a>(
+    paths: &'a OsStr,
+    program_name: &'a Path,
+) -> impl Iterator<Item = PathBuf> + 'a {
+    env::split_paths(paths)
+        .map(move |dir| dir.join(program_name))
+        .filter(|path| is_executable(path))
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
 
-    if !buf.is_empty() {
-        return Some(buf.as_bytes().iter().filter(|&&c| c == b'\n').count() + 1);
+    match path.metadata() {
+        Ok(metadata) => metadata.is_file() && metadata.permissions().mode() & 0o111 != 0,
+        Err(_) => false,
     }
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
 
-    None
+/// Returns the number of non-blank lines in a buffer, _e.g._ the output of a command
+/// that lists one item per line.
+///
+/// Trailing newlines and `\r\n` line endings don't affect the count, and an empty buffer
+/// yields `Some(0)`. As a failed command usually outputs nothing as well, its exit status
+/// should be checked before relying on a count of zero.
+pub fn count_lines<T>(buffer: T) -> Option<usize>
+where
+    T: std::string::ToString,
+{
+    Some(
+        buffer
+            .to_string()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .count(),
+    )
 }
 
 /**
@@ -139,11 +190,136 @@ pub fn list_dir_entries(path: &Path) -> Vec<PathBuf> {
     directory_entries
 }
 
+/**
+Returns `path` followed by the entries below it, down to `max_depth` levels of
+subdirectories, or the whole tree if `max_depth` is `None`.
+
+A depth of `0` only yields `path` itself, and a depth of `1` its direct entries as well.
+Entries for which `filter` returns `false` are neither returned nor descended into,
+which keeps large trees from being walked. Symbolic links to directories are
+returned but not followed.
+
+- If `path` does not exist, the function will return an empty `Vec`.
+
+# Example
+
+```
+use libmacchina::extra::list_dir_entries_depth;
+use std::path::Path;
+
+// The hidden entries of the current directory, and those of its hidden subdirectories.
+let hidden = list_dir_entries_depth(Path::new("."), Some(2), |entry| {
+    entry.file_name().map_or(true, |name| name.to_string_lossy().starts_with('.'))
+});
+```
+*/
+pub fn list_dir_entries_depth<F>(
+    path: &Path,
+    max_depth: Option<usize>,
+    mut filter: F,
+) -> Vec<PathBuf>
+where
+    F: FnMut(&Path) -> bool,
+{
+    if std::fs::symlink_metadata(path).is_err() {
+        return Vec::new();
+    }
+
+    let mut entries = vec![path.to_path_buf()];
+    let mut pending = vec![(path.to_path_buf(), 0)];
+    while let Some((dir, depth)) = pending.pop() {
+        if matches!(max_depth, Some(max_depth) if depth >= max_depth) {
+            continue;
+        }
+
+        let read_dir = match std::fs::read_dir(&dir) {
+            Ok(read_dir) => read_dir,
+            Err(_) => continue,
+        };
+
+        for entry in read_dir.flatten() {
+            let entry_path = entry.path();
+            if !filter(&entry_path) {
+                continue;
+            }
+
+            if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                pending.push((entry_path.clone(), depth + 1));
+            }
+
+            entries.push(entry_path);
+        }
+    }
+
+    entries
+}
+
 /// Returns the path's extension
 pub fn path_extension(path: &Path) -> Option<&str> {
     path.extension().and_then(OsStr::to_str)
 }
 
+/// Machine names of up to this many characters usually consist of the same identifier
+/// repeated, _e.g._ `lge lge (lge)`, so `normalize_machine_name` collapses their duplicate
+/// words. Longer names are kept as they are, since their words can legitimately repeat.
+pub const MACHINE_NAME_DEDUP_MAX_LEN: usize = 15;
+
+/**
+Builds a machine name of the form `<vendor> <family> (<product>)`, as shown by
+`GeneralReadout::machine()`.
+
+- Empty fields are left out.
+- The vendor is not repeated if the family already starts with it.
+- Duplicate words are collapsed in names of up to `MACHINE_NAME_DEDUP_MAX_LEN` characters.
+
+# Example
+
+```
+use libmacchina::extra::normalize_machine_name;
+
+assert_eq!(normalize_machine_name("google", "Pixel 7", "panther"), "google Pixel 7 (panther)");
+assert_eq!(normalize_machine_name("Xiaomi", "Xiaomi Redmi Note 8", "ginkgo"), "Xiaomi Redmi Note 8 (ginkgo)");
+```
+*/
+pub fn normalize_machine_name(vendor: &str, family: &str, product: &str) -> String {
+    let vendor = vendor.trim();
+    let product = product.trim();
+    let mut family = family.trim();
+
+    if !vendor.is_empty() {
+        if let Some(prefix) = family.get(..vendor.len()) {
+            let rest = &family[vendor.len()..];
+            if prefix.eq_ignore_ascii_case(vendor) && (rest.is_empty() || rest.starts_with(' ')) {
+                family = rest.trim_start();
+            }
+        }
+    }
+
+    let mut words: Vec<String> = vendor
+        .split_whitespace()
+        .chain(family.split_whitespace())
+        .map(String::from)
+        .collect();
+
+    if !product.is_empty() {
+        words.push(format!("({})", product));
+    }
+
+    let name = words.join(" ");
+    if name.len() > MACHINE_NAME_DEDUP_MAX_LEN {
+        return name;
+    }
+
+    let mut unique: Vec<String> = Vec::with_capacity(words.len());
+    for word in words {
+        if !unique.contains(&word) {
+            unique.push(word);
+        }
+    }
+
+    unique.join(" ")
+}
+
 pub fn common_shells() -> [&'static str; 10] {
     [
         "sh", "su", "nu", "bash", "fish", "dash", "tcsh", "zsh", "ksh", "csh",
@@ -159,9 +335,125 @@ where
     Ok(io::BufReader::new(file).lines())
 }
 
+/**
+Formats an uptime given in seconds, _e.g._ as returned by `GeneralReadout::uptime()`,
+using its two largest non-zero units.
+
+# Example
+
+```
+use libmacchina::extra::format_uptime;
+
+assert_eq!(format_uptime(273_600), "3d 4h");
+assert_eq!(format_uptime(725), "12m 5s");
+```
+*/
+pub fn format_uptime(seconds: usize) -> String {
+    const UNITS: [(usize, &str); 5] = [
+        (7 * 24 * 60 * 60, "w"),
+        (24 * 60 * 60, "d"),
+        (60 * 60, "h"),
+        (60, "m"),
+        (1, "s"),
+    ];
+
+    let mut remaining = seconds;
+    let parts: Vec<String> = UNITS
+        .iter()
+        .filter_map(|(length, unit)| {
+            let amount = remaining / length;
+            remaining %= length;
+
+            if amount != 0 {
+                Some(format!("{}{}", amount, unit))
+            } else {
+                None
+            }
+        })
+        .take(2)
+        .collect();
+
+    if parts.is_empty() {
+        return String::from("0s");
+    }
+
+    parts.join(" ")
+}
+
+/**
+Cleans up a processor's model name, _e.g._ as returned by `GeneralReadout::cpu_model_name()`,
+by removing trademark symbols, the redundant `CPU`/`Processor` words and the base clock speed.
+
+# Example
+
+```
+use libmacchina::extra::pretty_cpu_model_name;
+
+assert_eq!(
+    pretty_cpu_model_name("Intel(R) Core(TM) i5-8265U CPU @ 1.60GHz"),
+    "Intel Core i5-8265U"
+);
+```
+*/
+pub fn pretty_cpu_model_name(name: &str) -> String {
+    let name = name
+        .replace("(R)", "")
+        .replace("(r)", "")
+        .replace("(TM)", "")
+        .replace("(tm)", "");
+
+    // The clock speed trails the model name, e.g. "... CPU @ 1.60GHz".
+    let name = match name.split_once('@') {
+        Some((model, _)) => model,
+        None => &name,
+    };
+
+    name.split_whitespace()
+        .filter(|word| !matches!(*word, "CPU" | "Processor"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/**
+Formats an amount of memory given in kibibytes, _e.g._ as returned by `MemoryReadout`,
+rounded to one decimal in either binary (`KiB`, `MiB`, ...) or SI (`kB`, `MB`, ...) units.
+
+# Example
+
+```
+use libmacchina::extra::bytes_to_human;
+
+assert_eq!(bytes_to_human(3_879_731, true), "3.7 GiB");
+assert_eq!(bytes_to_human(3_879_731, false), "4.0 GB");
+```
+*/
+pub fn bytes_to_human(kib: u64, binary: bool) -> String {
+    const BINARY_UNITS: [&str; 8] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB"];
+    const SI_UNITS: [&str; 8] = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB"];
+
+    let (base, units) = if binary {
+        (1024_f64, BINARY_UNITS)
+    } else {
+        (1000_f64, SI_UNITS)
+    };
+
+    let mut value = kib as f64 * 1024_f64;
+    let mut unit = 0;
+
+    // Compare against the rounded value, so that e.g. 1023.99 KiB is shown as 1.0 MiB
+    // rather than 1024.0 KiB.
+    while (value * 10_f64).round() / 10_f64 >= base && unit < units.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", value, units[unit])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::TempDir;
 
     #[test]
     fn test_ucfirst() {
@@ -176,10 +468,135 @@ mod tests {
     #[test]
     fn test_pop_newline() {
         assert_eq!(pop_newline(String::from("Haha\n")), "Haha");
+        assert_eq!(pop_newline("100\r\n"), "100");
+        assert_eq!(pop_newline("100\n\n"), "100");
+        assert_eq!(pop_newline("100 \n"), "100");
+        assert_eq!(pop_newline("100"), "100");
     }
 
     #[test]
     fn test_path_extension() {
         assert_eq!(path_extension(Path::new("test.rs")).unwrap(), "rs");
     }
+
+    #[test]
+    fn test_format_uptime() {
+        assert_eq!(format_uptime(0), "0s");
+        assert_eq!(format_uptime(86_400), "1d");
+        assert_eq!(format_uptime(1_904_400), "3w 1d");
+    }
+
+    #[test]
+    fn test_bytes_to_human() {
+        assert_eq!(bytes_to_human(0, true), "0.0 B");
+        assert_eq!(bytes_to_human(1023, true), "1023.0 KiB");
+        assert_eq!(bytes_to_human(1024, true), "1.0 MiB");
+        assert_eq!(bytes_to_human(1024, false), "1.0 MB");
+        assert_eq!(bytes_to_human(1_048_575, true), "1.0 GiB");
+        assert_eq!(bytes_to_human(5 * 1024 * 1024 * 1024, true), "5.0 TiB");
+        assert_eq!(bytes_to_human(5 * 1024 * 1024 * 1024, false), "5.5 TB");
+        assert_eq!(bytes_to_human(u64::MAX, true), "16.0 ZiB");
+    }
+
+    #[test]
+    fn test_pretty_cpu_model_name() {
+        assert_eq!(
+            pretty_cpu_model_name("Intel(R)  Core(TM) i7-4790K CPU @ 4.00GHz"),
+            "Intel Core i7-4790K"
+        );
+        assert_eq!(
+            pretty_cpu_model_name("AMD Ryzen 7 5800X 8-Core Processor"),
+            "AMD Ryzen 7 5800X 8-Core"
+        );
+        assert_eq!(
+            pretty_cpu_model_name("Intel(R) Xeon(R) CPU E5-2680 v4 @ 2.40GHz"),
+            "Intel Xeon E5-2680 v4"
+        );
+        assert_eq!(
+            pretty_cpu_model_name("Qualcomm Technologies, Inc SM8250"),
+            "Qualcomm Technologies, Inc SM8250"
+        );
+    }
+
+    #[test]
+    fn test_normalize_machine_name() {
+        assert_eq!(normalize_machine_name("lge", "lge", "lge"), "lge (lge)");
+        assert_eq!(
+            normalize_machine_name("Samsung", "Samsung SM-G991B", "o1s"),
+            "Samsung SM-G991B (o1s)"
+        );
+        assert_eq!(
+            normalize_machine_name("OnePlus", "OnePlusNord", "avicii"),
+            "OnePlus OnePlusNord (avicii)"
+        );
+    }
+
+    #[test]
+    fn test_normalize_machine_name_empty_fields() {
+        assert_eq!(normalize_machine_name("", "", ""), "");
+        assert_eq!(normalize_machine_name("", "Pixel 7", ""), "Pixel 7");
+        assert_eq!(
+            normalize_machine_name("google", "", "panther"),
+            "google (panther)"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_executables_in() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let fixture = TempDir::new("which");
+        let (first, second) = (fixture.join("first"), fixture.create_dir("second"));
+        fixture.create_dir("first/tool");
+        for (dir, mode) in [("first", 0o644), ("second", 0o755)] {
+            let path = fixture.write(&format!("{}/python3", dir), "");
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+        }
+        let paths = env::join_paths([&first, &second]).unwrap();
+
+        let pythons: Vec<PathBuf> = executables_in(&paths, Path::new("python3")).collect();
+        assert_eq!(pythons, vec![second.join("python3")]);
+        assert_eq!(executables_in(&paths, Path::new("tool")).count(), 0);
+    }
+
+    #[test]
+    fn test_list_dir_entries_depth() {
+        let fixture = TempDir::new("depth");
+        fixture.create_dir("a/b/c");
+        fixture.create_dir("skip/d");
+        fixture.write("a/file", "");
+
+        let list = |max_depth: Option<usize>| {
+            let mut entries: Vec<PathBuf> =
+                list_dir_entries_depth(&fixture, max_depth, |entry| !entry.ends_with("skip"))
+                    .into_iter()
+                    .map(|entry| entry.strip_prefix(&fixture).unwrap().to_path_buf())
+                    .collect();
+            entries.sort();
+            entries
+        };
+
+        let root = list(Some(0));
+        let shallow = list(Some(1));
+        let unbounded = list(None);
+        let missing = list_dir_entries_depth(&fixture.join("missing"), None, |_| true);
+
+        let paths = |paths: &[&str]| -> Vec<PathBuf> { paths.iter().map(PathBuf::from).collect() };
+        assert_eq!(root, paths(&[""]));
+        assert_eq!(shallow, paths(&["", "a"]));
+        assert_eq!(unbounded, paths(&["", "a", "a/b", "a/b/c", "a/file"]));
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn test_count_lines() {
+        assert_eq!(count_lines(""), Some(0));
+        assert_eq!(count_lines("\n"), Some(0));
+        assert_eq!(count_lines(" \r\n\n"), Some(0));
+        assert_eq!(count_lines("a"), Some(1));
+        assert_eq!(count_lines("a\nb"), Some(2));
+        assert_eq!(count_lines("a\nb\n"), Some(2));
+        assert_eq!(count_lines("a\r\nb\r\n"), Some(2));
+    }
 }
@@ -3,7 +3,8 @@ use crate::shared;
 use crate::traits::*;
 use byte_unit::AdjustedByte;
 use std::fs;
-use std::path::PathBuf;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
 use sysctl::{Ctl, Sysctl};
 
 impl From<sqlite::Error> for ReadoutError {
@@ -38,6 +39,12 @@ pub struct FreeBSDProductReadout;
 
 pub struct FreeBSDPackageReadout;
 
+pub struct FreeBSDGpuReadout;
+
+pub struct FreeBSDDiskReadout;
+
+pub struct FreeBSDNetworkReadout;
+
 impl BatteryReadout for FreeBSDBatteryReadout {
     fn new() -> Self {
         FreeBSDBatteryReadout {
@@ -64,6 +71,7 @@ impl BatteryReadout for FreeBSDBatteryReadout {
                 if let Ok(to_int) = val.parse::<u8>() {
                     match to_int {
                         // https://lists.freebsd.org/pipermail/freebsd-acpi/2019-October/009753.html
+                        0 => return Ok(BatteryState::Full),
                         1 => return Ok(BatteryState::Discharging),
                         2 => return Ok(BatteryState::Charging),
                         _ => {
@@ -109,6 +117,10 @@ impl KernelReadout for FreeBSDKernelReadout {
     fn pretty_kernel(&self) -> Result<String, ReadoutError> {
         Err(ReadoutError::MetricNotAvailable)
     }
+
+    fn architecture(&self) -> Result<String, ReadoutError> {
+        shared::architecture()
+    }
 }
 
 impl GeneralReadout for FreeBSDGeneralReadout {
@@ -119,7 +131,7 @@ impl GeneralReadout for FreeBSDGeneralReadout {
         }
     }
 
-    fn resolution(&self) -> Result<String, ReadoutError> {
+    fn resolution(&self) -> Result<Vec<String>, ReadoutError> {
         Err(ReadoutError::MetricNotAvailable)
     }
 
@@ -135,6 +147,18 @@ impl GeneralReadout for FreeBSDGeneralReadout {
         shared::local_ip(interface)
     }
 
+    fn local_ips(&self, interface: Option<String>) -> Result<Vec<IpAddr>, ReadoutError> {
+        shared::local_ips(interface)
+    }
+
+    fn locale(&self) -> Result<String, ReadoutError> {
+        shared::locale()
+    }
+
+    fn timezone(&self) -> Result<String, ReadoutError> {
+        shared::timezone()
+    }
+
     fn username(&self) -> Result<String, ReadoutError> {
         shared::username()
     }
@@ -160,6 +184,22 @@ impl GeneralReadout for FreeBSDGeneralReadout {
         crate::shared::session()
     }
 
+    fn session_type(&self) -> Result<String, ReadoutError> {
+        shared::session_type()
+    }
+
+    fn theme(&self) -> Result<String, ReadoutError> {
+        shared::theme()
+    }
+
+    fn icons(&self) -> Result<String, ReadoutError> {
+        shared::icons()
+    }
+
+    fn accent_color(&self) -> Result<(u8, u8, u8), ReadoutError> {
+        shared::accent_color()
+    }
+
     fn window_manager(&self) -> Result<String, ReadoutError> {
         shared::window_manager()
     }
@@ -250,6 +290,10 @@ impl GeneralReadout for FreeBSDGeneralReadout {
         shared::cpu_physical_cores()
     }
 
+    fn load_average(&self) -> Result<(f64, f64, f64), ReadoutError> {
+        shared::load_average()
+    }
+
     fn cpu_usage(&self) -> Result<usize, ReadoutError> {
         shared::cpu_usage()
     }
@@ -342,7 +386,7 @@ impl PackageReadout for FreeBSDPackageReadout {
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
         let mut packages = Vec::new();
 
-        if extra::which("pkg") {
+        if Path::new("/var/db/pkg/local.sqlite").exists() {
             if let Some(c) = FreeBSDPackageReadout::count_pkg() {
                 packages.push((PackageManager::Pkg, c));
             }
@@ -358,7 +402,27 @@ impl PackageReadout for FreeBSDPackageReadout {
     }
 }
 
+impl GpuReadout for FreeBSDGpuReadout {
+    fn new() -> Self {
+        FreeBSDGpuReadout
+    }
+}
+
+impl DiskReadout for FreeBSDDiskReadout {
+    fn new() -> Self {
+        FreeBSDDiskReadout
+    }
+}
+
+impl NetworkReadout for FreeBSDNetworkReadout {
+    fn new() -> Self {
+        FreeBSDNetworkReadout
+    }
+}
+
 impl FreeBSDPackageReadout {
+    /// Returns the number of installed packages by counting the rows of the
+    /// `packages` table of pkg's database, rather than running `pkg info`.
     fn count_pkg() -> Option<usize> {
         let connection = sqlite::open("/var/db/pkg/local.sqlite");
 
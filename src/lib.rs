@@ -1,8 +1,5 @@
 use cfg_if::cfg_if;
 
-#[macro_use]
-extern crate lazy_static;
-
 cfg_if! {
     if #[cfg(all(target_os = "linux", feature = "openwrt"))] {
         mod openwrt;
@@ -13,6 +10,9 @@ cfg_if! {
         pub type GeneralReadout = openwrt::OpenWrtGeneralReadout;
         pub type ProductReadout = openwrt::OpenWrtProductReadout;
         pub type PackageReadout = openwrt::OpenWrtPackageReadout;
+        pub type GpuReadout = openwrt::OpenWrtGpuReadout;
+        pub type DiskReadout = openwrt::OpenWrtDiskReadout;
+        pub type NetworkReadout = openwrt::OpenWrtNetworkReadout;
     } else if #[cfg(all(target_os = "linux", not(feature = "openwrt")))] {
         mod linux;
         mod winman;
@@ -23,6 +23,9 @@ cfg_if! {
         pub type GeneralReadout = linux::LinuxGeneralReadout;
         pub type ProductReadout = linux::LinuxProductReadout;
         pub type PackageReadout = linux::LinuxPackageReadout;
+        pub type GpuReadout = linux::LinuxGpuReadout;
+        pub type DiskReadout = linux::LinuxDiskReadout;
+        pub type NetworkReadout = linux::LinuxNetworkReadout;
     } else if #[cfg(target_os = "macos")] {
         mod macos;
 
@@ -32,6 +35,9 @@ cfg_if! {
         pub type GeneralReadout = macos::MacOSGeneralReadout;
         pub type ProductReadout = macos::MacOSProductReadout;
         pub type PackageReadout = macos::MacOSPackageReadout;
+        pub type GpuReadout = macos::MacOSGpuReadout;
+        pub type DiskReadout = macos::MacOSDiskReadout;
+        pub type NetworkReadout = macos::MacOSNetworkReadout;
     } else if #[cfg(target_os = "netbsd")] {
         mod netbsd;
         mod winman;
@@ -42,6 +48,9 @@ cfg_if! {
         pub type GeneralReadout = netbsd::NetBSDGeneralReadout;
         pub type ProductReadout = netbsd::NetBSDProductReadout;
         pub type PackageReadout = netbsd::NetBSDPackageReadout;
+        pub type GpuReadout = netbsd::NetBSDGpuReadout;
+        pub type DiskReadout = netbsd::NetBSDDiskReadout;
+        pub type NetworkReadout = netbsd::NetBSDNetworkReadout;
     } else if #[cfg(target_os = "windows")] {
         mod windows;
 
@@ -51,6 +60,9 @@ cfg_if! {
         pub type GeneralReadout = windows::WindowsGeneralReadout;
         pub type ProductReadout = windows::WindowsProductReadout;
         pub type PackageReadout = windows::WindowsPackageReadout;
+        pub type GpuReadout = windows::WindowsGpuReadout;
+        pub type DiskReadout = windows::WindowsDiskReadout;
+        pub type NetworkReadout = windows::WindowsNetworkReadout;
     } else if #[cfg(target_os = "android")] {
         mod android;
 
@@ -60,6 +72,9 @@ cfg_if! {
         pub type GeneralReadout = android::AndroidGeneralReadout;
         pub type ProductReadout = android::AndroidProductReadout;
         pub type PackageReadout = android::AndroidPackageReadout;
+        pub type GpuReadout = android::AndroidGpuReadout;
+        pub type DiskReadout = android::AndroidDiskReadout;
+        pub type NetworkReadout = android::AndroidNetworkReadout;
     } else if #[cfg(target_os = "freebsd")] {
         mod freebsd;
         mod winman;
@@ -70,11 +85,23 @@ cfg_if! {
         pub type GeneralReadout = freebsd::FreeBSDGeneralReadout;
         pub type ProductReadout = freebsd::FreeBSDProductReadout;
         pub type PackageReadout = freebsd::FreeBSDPackageReadout;
+        pub type GpuReadout = freebsd::FreeBSDGpuReadout;
+        pub type DiskReadout = freebsd::FreeBSDDiskReadout;
+        pub type NetworkReadout = freebsd::FreeBSDNetworkReadout;
     } else {
         compiler_error!("This platform is currently not supported by libmacchina.");
     }
 }
 
+/// All readouts of the current platform, as returned by `Readouts::new()`.
+///
+/// ```
+/// use libmacchina::traits::GeneralReadout as _;
+/// use libmacchina::Readouts;
+///
+/// let readouts = Readouts::new();
+/// println!("{:?}", readouts.general.uptime().ok());
+/// ```
 pub struct Readouts {
     pub battery: BatteryReadout,
     pub kernel: KernelReadout,
@@ -82,6 +109,32 @@ pub struct Readouts {
     pub general: GeneralReadout,
     pub product: ProductReadout,
     pub packages: PackageReadout,
+    pub gpu: GpuReadout,
+    pub disk: DiskReadout,
+    pub network: NetworkReadout,
+}
+
+impl Readouts {
+    /// Creates every readout of the current platform.
+    pub fn new() -> Self {
+        Readouts {
+            battery: traits::BatteryReadout::new(),
+            kernel: traits::KernelReadout::new(),
+            memory: traits::MemoryReadout::new(),
+            general: traits::GeneralReadout::new(),
+            product: traits::ProductReadout::new(),
+            packages: traits::PackageReadout::new(),
+            gpu: traits::GpuReadout::new(),
+            disk: traits::DiskReadout::new(),
+            network: traits::NetworkReadout::new(),
+        }
+    }
+}
+
+impl Default for Readouts {
+    fn default() -> Self {
+        Readouts::new()
+    }
 }
 
 pub fn version() -> &'static str {
@@ -92,7 +145,12 @@ pub fn version() -> &'static str {
     }
 }
 
+pub mod boxed;
 pub mod dirs;
 pub mod extra;
+#[cfg(feature = "async")]
+pub mod nonblocking;
 mod shared;
+#[cfg(test)]
+mod test_utils;
 pub mod traits;
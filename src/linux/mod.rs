@@ -5,11 +5,14 @@ use crate::extra::list_dir_entries;
 use crate::traits::*;
 use byte_unit::AdjustedByte;
 use itertools::Itertools;
+use std::ffi::CStr;
 use std::fs;
 use std::fs::read_dir;
-use std::io::{BufRead, BufReader};
+use std::net::IpAddr;
 use std::path::{Path, PathBuf};
-use std::process::{Command, Stdio};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::Duration;
 use sysctl::{Ctl, Sysctl};
 use sysinfo_ffi::sysinfo;
 
@@ -29,6 +32,8 @@ pub struct LinuxKernelReadout {
 pub struct LinuxGeneralReadout {
     hostname_ctl: Option<Ctl>,
     sysinfo: sysinfo,
+    cpu_model_name: Mutex<Option<String>>,
+    cpu_jiffies: Option<(u64, u64)>,
 }
 
 pub struct LinuxMemoryReadout {
@@ -38,72 +43,89 @@ pub struct LinuxProductReadout;
 
 pub struct LinuxPackageReadout;
 
+pub struct LinuxGpuReadout;
+
+pub struct LinuxDiskReadout;
+
+pub struct LinuxNetworkReadout;
+
 impl BatteryReadout for LinuxBatteryReadout {
     fn new() -> Self {
         LinuxBatteryReadout
     }
 
     fn percentage(&self) -> Result<u8, ReadoutError> {
-        let mut dirs = list_dir_entries(&PathBuf::from("/sys/class/power_supply"));
-        let index = dirs
-            .iter()
-            .position(|f| f.to_string_lossy().contains("ADP"));
-        if let Some(i) = index {
-            dirs.remove(i);
-        }
-
-        let bat = dirs.first();
-        if let Some(b) = bat {
-            let path_to_capacity = b.join("capacity");
-            let percentage_text = extra::pop_newline(fs::read_to_string(path_to_capacity)?);
-            let percentage_parsed = percentage_text.parse::<u8>();
-
-            match percentage_parsed {
-                Ok(p) => return Ok(p),
-                Err(e) => {
-                    return Err(ReadoutError::Other(format!(
-                        "Could not parse the value '{}' into a \
-            digit: {:?}",
-                        percentage_text, e
-                    )))
-                }
-            };
-        }
+        crate::shared::combined_battery_percentage(&LinuxBatteryReadout::batteries()?)
+    }
 
-        Err(ReadoutError::Other("No batteries detected.".to_string()))
+    fn percentage_all(&self) -> Result<Vec<(String, u8)>, ReadoutError> {
+        Ok(crate::shared::battery_percentages())
     }
 
     fn status(&self) -> Result<BatteryState, ReadoutError> {
-        let mut dirs = list_dir_entries(&PathBuf::from("/sys/class/power_supply"));
-        let index = dirs
-            .iter()
-            .position(|f| f.to_string_lossy().contains("ADP"));
-        if let Some(i) = index {
-            dirs.remove(i);
-        }
+        crate::shared::combined_battery_state(&LinuxBatteryReadout::batteries()?)
+    }
 
-        let bat = dirs.first();
-        if let Some(b) = bat {
-            let path_to_status = b.join("status");
-            let status_text =
-                extra::pop_newline(fs::read_to_string(path_to_status)?).to_lowercase();
-
-            match &status_text[..] {
-                "charging" => return Ok(BatteryState::Charging),
-                "discharging" | "full" => return Ok(BatteryState::Discharging),
-                s => {
-                    return Err(ReadoutError::Other(format!(
-                        "Got an unexpected value \"{}\" reading battery status",
-                        s,
-                    )))
-                }
-            }
-        }
+    fn health(&self) -> Result<u8, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        crate::shared::battery_health(&bat)
+    }
+
+    fn technology(&self) -> Result<String, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        crate::shared::battery_technology(&bat)
+    }
+
+    fn capacity_design(&self) -> Result<u64, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        crate::shared::battery_capacity_design(&bat)
+    }
+
+    fn time_remaining(&self) -> Result<u32, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        crate::shared::battery_time_remaining(&bat, crate::shared::battery_state(&bat)?)
+    }
 
-        Err(ReadoutError::Other("No batteries detected.".to_string()))
+    fn power_draw(&self) -> Result<f32, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        crate::shared::battery_power_draw(&bat, crate::shared::battery_state(&bat)?)
+    }
+
+    fn voltage(&self) -> Result<f32, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        crate::shared::battery_voltage(&bat)
+    }
+
+    fn temperature(&self) -> Result<f32, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        crate::shared::battery_temperature(&bat)
+    }
+
+    fn all(&self) -> Result<BatteryInfo, ReadoutError> {
+        let bat = LinuxBatteryReadout::primary_battery()?;
+        let batteries = LinuxBatteryReadout::batteries()?;
+
+        Ok(BatteryInfo {
+            percentage: crate::shared::combined_battery_percentage(&batteries)?,
+            state: crate::shared::combined_battery_state(&batteries)?,
+            health: crate::shared::battery_health(&bat).ok(),
+        })
+    }
+}
+
+impl LinuxBatteryReadout {
+    /// Returns the `/sys/class/power_supply` entry of the first supply whose type is
+    /// `Battery`, falling back to `battery_dir()` if no supply reports its type.
+    fn primary_battery() -> Result<PathBuf, ReadoutError> {
+        match crate::shared::battery_dirs().into_iter().next() {
+            Some(battery) => Ok(battery),
+            None => LinuxBatteryReadout::battery_dir(),
+        }
     }
 
-    fn health(&self) -> Result<u64, ReadoutError> {
+    /// Returns the `/sys/class/power_supply` entry of the first battery,
+    /// skipping the AC adapter.
+    fn battery_dir() -> Result<PathBuf, ReadoutError> {
         let mut dirs = list_dir_entries(&PathBuf::from("/sys/class/power_supply"));
         let index = dirs
             .iter()
@@ -112,33 +134,21 @@ impl BatteryReadout for LinuxBatteryReadout {
             dirs.remove(i);
         }
 
-        let bat = dirs.first();
-        if let Some(b) = bat {
-            let energy_full =
-                extra::pop_newline(fs::read_to_string(b.join("energy_full"))?).parse::<u64>();
-
-            let energy_full_design =
-                extra::pop_newline(fs::read_to_string(b.join("energy_full_design"))?)
-                    .parse::<u64>();
-
-            match (energy_full, energy_full_design) {
-                (Ok(mut ef), Ok(efd)) => {
-                    if ef > efd {
-                        ef = efd;
-                        return Ok(((ef as f64 / efd as f64) * 100_f64) as u64);
-                    }
+        match dirs.into_iter().next() {
+            Some(b) => Ok(b),
+            None => Err(ReadoutError::MetricNotAvailable),
+        }
+    }
 
-                    return Ok(((ef as f64 / efd as f64) * 100_f64) as u64);
-                }
-                _ => {
-                    return Err(ReadoutError::Other(
-                        "Error calculating battery health.".to_string(),
-                    ))
-                }
-            }
+    /// Returns the `/sys/class/power_supply` entries of every battery, falling back to
+    /// `battery_dir()` on systems whose supplies don't report their type.
+    fn batteries() -> Result<Vec<PathBuf>, ReadoutError> {
+        let batteries = crate::shared::battery_dirs();
+        if batteries.is_empty() {
+            return Ok(vec![LinuxBatteryReadout::battery_dir()?]);
         }
 
-        Err(ReadoutError::Other("No batteries detected.".to_string()))
+        Ok(batteries)
     }
 }
 
@@ -165,6 +175,114 @@ impl KernelReadout for LinuxKernelReadout {
             .ok_or(ReadoutError::MetricNotAvailable)?
             .value_string()?)
     }
+
+    fn architecture(&self) -> Result<String, ReadoutError> {
+        crate::shared::architecture()
+    }
+
+    fn libc_version(&self) -> Result<String, ReadoutError> {
+        LinuxKernelReadout::glibc_version()
+            .or_else(LinuxKernelReadout::musl_version)
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    fn hostname(&self) -> Result<String, ReadoutError> {
+        crate::shared::hostname()
+    }
+}
+
+impl LinuxKernelReadout {
+    /// Returns the version of the GNU C library this program is running against.
+    #[cfg(target_env = "gnu")]
+    fn glibc_version() -> Option<String> {
+        extern "C" {
+            fn gnu_get_libc_version() -> *const libc::c_char;
+        }
+
+        let version = unsafe { CStr::from_ptr(gnu_get_libc_version()) };
+        Some(format!("glibc {}", version.to_str().ok()?))
+    }
+
+    #[cfg(not(target_env = "gnu"))]
+    fn glibc_version() -> Option<String> {
+        None
+    }
+
+    /// Returns the version of musl, which its dynamic loader (`/lib/ld-musl-*.so*`) prints
+    /// to stderr when invoked without a program, as does `ldd --version`, _e.g._
+    ///
+    /// ```text
+    /// musl libc (x86_64)
+    /// Version 1.2.4
+    /// ```
+    fn musl_version() -> Option<String> {
+        let loader = list_dir_entries(Path::new("/lib"))
+            .into_iter()
+            .find(
+                |entry| match entry.file_name().and_then(|name| name.to_str()) {
+                    Some(name) => name.starts_with("ld-musl-") && name.contains(".so"),
+                    None => false,
+                },
+            );
+
+        let mut commands = Vec::new();
+        if let Some(loader) = loader {
+            commands.push(Command::new(loader));
+        }
+
+        let mut ldd = Command::new("ldd");
+        ldd.arg("--version");
+        commands.push(ldd);
+
+        commands.into_iter().find_map(|mut command| {
+            let output =
+                crate::shared::run_with_timeout(&mut command, crate::shared::COMMAND_TIMEOUT)?;
+
+            let stderr = String::from_utf8(output.stderr).ok()?;
+            if !stderr.starts_with("musl libc") {
+                return None;
+            }
+
+            match stderr
+                .lines()
+                .find_map(|line| line.strip_prefix("Version "))
+            {
+                Some(version) => Some(format!("musl {}", version.trim())),
+                None => Some(String::from("musl")),
+            }
+        })
+    }
+}
+
+impl LinuxGeneralReadout {
+    /// Returns the current resolution of every connected output reported by `xrandr`, _e.g._
+    /// from lines such as `HDMI-1 connected primary 1920x1080+0+0 ...`.
+    fn xrandr_resolutions() -> Option<Vec<String>> {
+        if std::env::var_os("DISPLAY").is_none() || !extra::which("xrandr") {
+            return None;
+        }
+
+        let output = crate::shared::run_with_timeout(
+            Command::new("xrandr").arg("--current"),
+            crate::shared::COMMAND_TIMEOUT,
+        )?;
+
+        let resolutions: Vec<String> = String::from_utf8(output.stdout)
+            .ok()?
+            .lines()
+            .filter(|line| line.contains(" connected"))
+            .filter_map(|line| {
+                let geometry = line.split_whitespace().find(|word| word.contains('+'))?;
+                geometry.split('+').next().map(String::from)
+            })
+            .collect();
+
+        if resolutions.is_empty() {
+            return None;
+        }
+
+        Some(resolutions)
+    }
 }
 
 impl GeneralReadout for LinuxGeneralReadout {
@@ -172,6 +290,8 @@ impl GeneralReadout for LinuxGeneralReadout {
         LinuxGeneralReadout {
             hostname_ctl: Ctl::new("kernel.hostname").ok(),
             sysinfo: sysinfo::new(),
+            cpu_model_name: Mutex::new(None),
+            cpu_jiffies: None,
         }
     }
 
@@ -212,33 +332,38 @@ impl GeneralReadout for LinuxGeneralReadout {
         )))
     }
 
-    fn resolution(&self) -> Result<String, ReadoutError> {
-        let drm = Path::new("/sys/class/drm");
-        if drm.is_dir() {
-            let mut resolutions: Vec<String> = Vec::new();
-
-            // Iterate through symbolic links in /sys/class/drm
-            for entry in extra::list_dir_entries(drm) {
-                if entry.read_link().is_ok() {
-                    // Append modes to /sys/class/drm/<device>/
-                    let modes = entry.join("modes");
-                    if modes.is_file() {
-                        if let Ok(file) = std::fs::File::open(modes) {
-                            // Push the first line (if not empty) to the resolution vector
-                            if let Some(Ok(str)) = BufReader::new(file).lines().next() {
-                                resolutions.push(str);
-                            }
-                        }
-                    }
-                }
-            }
-
-            return Ok(resolutions.join(", "));
+    fn resolution(&self) -> Result<Vec<String>, ReadoutError> {
+        let resolutions = crate::shared::drm_resolutions();
+        if !resolutions.is_empty() {
+            return Ok(resolutions);
         }
 
-        Err(ReadoutError::Other(
-            "Could not obtain screen resolution from /sys/class/drm".to_string(),
-        ))
+        // Proprietary drivers, e.g. NVIDIA's, don't always expose their connectors through DRM.
+        LinuxGeneralReadout::xrandr_resolutions().ok_or_else(|| {
+            ReadoutError::Other(
+                "Could not obtain screen resolution from /sys/class/drm or xrandr".to_string(),
+            )
+        })
+    }
+
+    fn locale(&self) -> Result<String, ReadoutError> {
+        crate::shared::locale()
+    }
+
+    fn timezone(&self) -> Result<String, ReadoutError> {
+        crate::shared::timezone()
+    }
+
+    fn process_count(&self) -> Result<usize, ReadoutError> {
+        crate::shared::process_count()
+    }
+
+    fn loaded_modules(&self) -> Result<Vec<String>, ReadoutError> {
+        crate::shared::loaded_modules()
+    }
+
+    fn boot_time(&self) -> Result<u64, ReadoutError> {
+        crate::shared::boot_time()
     }
 
     fn username(&self) -> Result<String, ReadoutError> {
@@ -255,9 +380,12 @@ impl GeneralReadout for LinuxGeneralReadout {
 
     fn distribution(&self) -> Result<String, ReadoutError> {
         use os_release::OsRelease;
-        let content = OsRelease::new()?;
+        let content = OsRelease::new()
+            .map_err(|e| ReadoutError::Other(format!("Could not read /etc/os-release: {}", e)))?;
 
-        if !content.version.is_empty() {
+        if !content.pretty_name.is_empty() {
+            return Ok(content.pretty_name);
+        } else if !content.version.is_empty() {
             return Ok(format!("{} {}", content.name, content.version));
         } else if !content.version_id.is_empty() {
             return Ok(format!("{} {}", content.name, content.version_id));
@@ -266,137 +394,163 @@ impl GeneralReadout for LinuxGeneralReadout {
         Ok(content.name)
     }
 
+    fn os_logo_id(&self) -> Result<String, ReadoutError> {
+        use os_release::OsRelease;
+        let content = OsRelease::new()
+            .map_err(|e| ReadoutError::Other(format!("Could not read /etc/os-release: {}", e)))?;
+
+        if !content.id.is_empty() {
+            return Ok(content.id);
+        }
+
+        match content.id_like.split_whitespace().next() {
+            Some(id_like) => Ok(id_like.to_string()),
+            None => Err(ReadoutError::Other(String::from(
+                "Neither ID nor ID_LIKE are set in /etc/os-release.",
+            ))),
+        }
+    }
+
     fn local_ip(&self, interface: Option<String>) -> Result<String, ReadoutError> {
         crate::shared::local_ip(interface)
     }
 
+    fn local_ips(&self, interface: Option<String>) -> Result<Vec<IpAddr>, ReadoutError> {
+        crate::shared::local_ips(interface)
+    }
+
     fn desktop_environment(&self) -> Result<String, ReadoutError> {
         crate::shared::desktop_environment()
     }
 
+    fn desktop_environment_version(&self) -> Result<String, ReadoutError> {
+        crate::shared::desktop_environment_version()
+    }
+
     fn session(&self) -> Result<String, ReadoutError> {
         crate::shared::session()
     }
 
+    fn session_type(&self) -> Result<String, ReadoutError> {
+        crate::shared::session_type()
+    }
+
+    fn theme(&self) -> Result<String, ReadoutError> {
+        crate::shared::theme()
+    }
+
+    fn icons(&self) -> Result<String, ReadoutError> {
+        crate::shared::icons()
+    }
+
+    fn accent_color(&self) -> Result<(u8, u8, u8), ReadoutError> {
+        crate::shared::accent_color()
+    }
+
     fn window_manager(&self) -> Result<String, ReadoutError> {
         crate::shared::window_manager()
     }
 
     fn terminal(&self) -> Result<String, ReadoutError> {
-        // This function returns the PPID of a given PID:
-        //  - The file used to extract this data: /proc/<pid>/status
-        //  - This function parses and returns the value of the ppid line.
-        fn get_parent(pid: i32) -> i32 {
-            let process_path = PathBuf::from("/proc").join(pid.to_string()).join("status");
-            let file = fs::File::open(process_path);
-            match file {
-                Ok(content) => {
-                    let reader = BufReader::new(content);
-                    for line in reader.lines().flatten() {
-                        if line.to_uppercase().starts_with("PPID") {
-                            let s_mem_kb: String =
-                                line.chars().filter(|c| c.is_digit(10)).collect();
-                            return s_mem_kb.parse::<i32>().unwrap_or(-1);
-                        }
-                    }
+        crate::shared::terminal()
+    }
 
-                    -1
-                }
+    fn shell(&self, format: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
+        crate::shared::shell(format, kind)
+    }
 
-                Err(_) => -1,
-            }
-        }
+    fn cpu_model_name(&self) -> Result<String, ReadoutError> {
+        // The model name can't change, so /proc/cpuinfo is only read until it succeeds.
+        let mut cached = self.cpu_model_name.lock().map_err(|_| {
+            ReadoutError::Other(String::from("The CPU model name cache is poisoned."))
+        })?;
 
-        // This function returns the name associated with a given PPID
-        fn terminal_name() -> String {
-            let mut terminal_pid = get_parent(unsafe { libc::getppid() });
+        if let Some(model_name) = cached.as_ref() {
+            return Ok(model_name.clone());
+        }
 
-            let path = PathBuf::from("/proc")
-                .join(terminal_pid.to_string())
-                .join("comm");
+        let model_name = crate::shared::cpu_model_name();
+        if !model_name.is_empty() {
+            *cached = Some(model_name.clone());
+        }
 
-            // The below loop will traverse /proc to find the
-            // terminal inside of which the user is operating
-            if let Ok(mut terminal_name) = fs::read_to_string(path) {
-                // Any command_name we find that matches
-                // one of the elements within this table
-                // is effectively ignored
-                while extra::common_shells().contains(&terminal_name.replace("\n", "").as_str()) {
-                    let ppid = get_parent(terminal_pid);
-                    terminal_pid = ppid;
+        Ok(model_name)
+    }
 
-                    let path = PathBuf::from("/proc").join(ppid.to_string()).join("comm");
+    fn load_average(&self) -> Result<(f64, f64, f64), ReadoutError> {
+        crate::shared::load_average()
+    }
 
-                    if let Ok(comm) = fs::read_to_string(path) {
-                        terminal_name = comm;
-                    }
-                }
+    fn cpu_usage_interval(&self, interval: Duration) -> Result<usize, ReadoutError> {
+        crate::shared::cpu_usage(interval)
+    }
 
-                return terminal_name;
-            }
+    fn cpu_usage_sampled(&mut self) -> Result<usize, ReadoutError> {
+        let current = crate::shared::total_jiffies()?;
 
-            String::new()
+        match self.cpu_jiffies.replace(current) {
+            Some(previous) => Ok(crate::shared::jiffies_usage(previous, current)),
+            None => self.cpu_usage(),
         }
+    }
 
-        let terminal = terminal_name();
+    fn cpu_features(&self) -> Result<Vec<String>, ReadoutError> {
+        crate::shared::cpu_features()
+    }
 
-        if terminal.is_empty() {
-            return Err(ReadoutError::Other(
-                "Querying terminal information failed".to_string(),
-            ));
-        }
+    fn cpu_vendor(&self) -> Result<String, ReadoutError> {
+        crate::shared::cpu_vendor()
+    }
 
-        Ok(terminal)
+    fn cpu_physical_cores(&self) -> Result<usize, ReadoutError> {
+        crate::shared::cpu_physical_cores()
     }
 
-    fn shell(&self, format: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
-        crate::shared::shell(format, kind)
+    fn cpu_cores(&self) -> Result<usize, ReadoutError> {
+        Ok(unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) } as usize)
     }
 
-    fn cpu_model_name(&self) -> Result<String, ReadoutError> {
-        Ok(crate::shared::cpu_model_name())
+    fn cpu_cores_by_cluster(&self) -> Result<Vec<(u64, usize)>, ReadoutError> {
+        crate::shared::cpu_cores_by_cluster()
     }
 
-    fn cpu_usage(&self) -> Result<usize, ReadoutError> {
-        let mut info = self.sysinfo;
-        let info_ptr: *mut sysinfo = &mut info;
-        let ret = unsafe { sysinfo(info_ptr) };
-        if ret != -1 {
-            let f_load = 1f64 / (1 << libc::SI_LOAD_SHIFT) as f64;
-            let cpu_usage = info.loads[0] as f64 * f_load;
-            let cpu_usage_u =
-                (cpu_usage / self.cpu_cores().unwrap() as f64 * 100.0).round() as usize;
-            Ok(cpu_usage_u as usize)
-        } else {
-            Err(ReadoutError::Other(
-                "Failed to get system statistics".to_string(),
-            ))
-        }
+    fn cpu_temperature(&self) -> Result<f32, ReadoutError> {
+        crate::shared::cpu_temperature()
     }
 
-    fn cpu_physical_cores(&self) -> Result<usize, ReadoutError> {
-        use std::io::{BufRead, BufReader};
-        if let Ok(content) = fs::File::open("/proc/cpuinfo") {
-            let reader = BufReader::new(content);
-            for line in reader.lines().flatten() {
-                if line.to_lowercase().starts_with("cpu cores") {
-                    let cores = line
-                        .split(':')
-                        .nth(1)
-                        .unwrap()
-                        .trim()
-                        .parse::<usize>()
-                        .unwrap();
-                    return Ok(cores);
-                }
-            }
-        }
+    fn virtualization(&self) -> Result<String, ReadoutError> {
+        crate::shared::virtualization()
+    }
 
-        Err(ReadoutError::MetricNotAvailable)
+    fn audio_server(&self) -> Result<String, ReadoutError> {
+        crate::shared::audio_server()
     }
 
-    fn cpu_cores(&self) -> Result<usize, ReadoutError> {
-        Ok(unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) } as usize)
+    fn gpu_usage(&self) -> Result<usize, ReadoutError> {
+        // Exposed by the amdgpu driver, and by some others, under the card's device.
+        LinuxGpuReadout::drm_cards()
+            .iter()
+            .find_map(|card| {
+                crate::shared::read_sysfs_u64(&card.join("device/gpu_busy_percent")).ok()
+            })
+            .map(|usage| usage.min(100) as usize)
+            .ok_or(ReadoutError::MetricNotAvailable)
+    }
+
+    fn gpu_driver(&self) -> Result<Vec<String>, ReadoutError> {
+        LinuxGpuReadout::drivers()
+    }
+
+    fn gpu_temperature(&self) -> Result<Vec<Result<f32, ReadoutError>>, ReadoutError> {
+        Ok(LinuxGpuReadout::temperatures())
+    }
+
+    fn cpu_frequency(&self) -> Result<u64, ReadoutError> {
+        crate::shared::cpu_frequency()
+    }
+
+    fn cpu_usage_per_core_interval(&self, interval: Duration) -> Result<Vec<usize>, ReadoutError> {
+        crate::shared::cpu_usage_per_core(interval)
     }
 
     fn uptime(&self) -> Result<usize, ReadoutError> {
@@ -443,6 +597,10 @@ impl GeneralReadout for LinuxGeneralReadout {
         Ok(version)
     }
 
+    fn host_model(&self) -> Result<String, ReadoutError> {
+        crate::shared::host_model(Path::new("/sys/class/dmi/id"))
+    }
+
     fn disk_space(&self) -> Result<(AdjustedByte, AdjustedByte), ReadoutError> {
         crate::shared::disk_space(String::from("/"))
     }
@@ -494,21 +652,51 @@ impl MemoryReadout for LinuxMemoryReadout {
         }
     }
 
+    fn available(&self) -> Result<u64, ReadoutError> {
+        crate::shared::get_meminfo_value("MemAvailable")
+    }
+
     fn cached(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("Cached"))
+        crate::shared::get_meminfo_value("Cached")
     }
 
     fn reclaimable(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("SReclaimable"))
+        crate::shared::get_meminfo_value("SReclaimable")
     }
 
     fn used(&self) -> Result<u64, ReadoutError> {
-        let total = self.total().unwrap();
-        let free = self.free().unwrap();
-        let cached = self.cached().unwrap();
-        let reclaimable = self.reclaimable().unwrap();
-        let buffers = self.buffers().unwrap();
-        Ok(total - free - cached - reclaimable - buffers)
+        let total = self.total()?;
+        let free = self.free()?;
+        let cached = self.cached()?;
+        let reclaimable = self.reclaimable()?;
+        let buffers = self.buffers()?;
+
+        Ok(crate::shared::used_memory(
+            total,
+            free,
+            cached,
+            reclaimable,
+            buffers,
+        ))
+    }
+
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        crate::shared::get_meminfo_value("SwapTotal")
+    }
+
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        let total = self.swap_total()?;
+        let free = crate::shared::get_meminfo_value("SwapFree")?;
+
+        Ok(total.saturating_sub(free))
+    }
+
+    fn zram_used(&self) -> Result<u64, ReadoutError> {
+        Ok(crate::shared::zram_usage(Path::new("/sys/block"))?.0)
+    }
+
+    fn zram_total(&self) -> Result<u64, ReadoutError> {
+        Ok(crate::shared::zram_usage(Path::new("/sys/block"))?.1)
     }
 }
 
@@ -534,6 +722,31 @@ impl ProductReadout for LinuxProductReadout {
             "/sys/class/dmi/id/product_name",
         )?))
     }
+
+    fn serial_number(&self) -> Result<String, ReadoutError> {
+        // product_serial is only readable by root.
+        match crate::shared::read_sysfs_string(Path::new("/sys/class/dmi/id/product_serial")) {
+            Ok(serial) if !serial.trim().is_empty() => Ok(serial.trim().to_string()),
+            Ok(_) => Err(ReadoutError::Other(String::from(
+                "Your manufacturer may have not specified your machine's serial number.",
+            ))),
+            Err(ReadoutError::FileRead { source, .. })
+                if source.kind() == std::io::ErrorKind::PermissionDenied =>
+            {
+                Err(ReadoutError::MetricNotAvailable)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl LinuxPackageReadout {
+    /// Returns the number of installed snaps like `count_pkgs()` does, but leaves out
+    /// base snaps such as `core22`, `snapd` and `bare`, which only provide a runtime
+    /// for the applications.
+    pub fn count_snap_apps(&self) -> Option<usize> {
+        crate::shared::count_snaps(Path::new("/snap"), Path::new("/var/lib/snapd/snaps"), true)
+    }
 }
 
 impl PackageReadout for LinuxPackageReadout {
@@ -542,7 +755,7 @@ impl PackageReadout for LinuxPackageReadout {
     }
 
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
-        let mut packages = Vec::new();
+        let mut counters: Vec<crate::shared::PackageCounter> = Vec::new();
         // Instead of having a condition for each distribution.
         // we will try and extract package count by checking
         // if a certain package manager is installed
@@ -550,100 +763,106 @@ impl PackageReadout for LinuxPackageReadout {
         // It might seem weird that we're using `if` rather than `else if`
         // but there are some people who have multiple
         // distribution-specific package managers installed
-        if extra::which("pacman") {
-            if let Some(c) = LinuxPackageReadout::count_pacman() {
-                packages.push((PackageManager::Pacman, c));
-            }
+        if Path::new("/var/lib/pacman/local").is_dir() {
+            counters.push((PackageManager::Pacman, LinuxPackageReadout::count_pacman));
         }
         if extra::which("dpkg") {
-            if let Some(c) = LinuxPackageReadout::count_dpkg() {
-                packages.push((PackageManager::Dpkg, c));
-            }
+            counters.push((PackageManager::Dpkg, LinuxPackageReadout::count_dpkg));
         }
-        if extra::which("qlist") {
-            if let Some(c) = LinuxPackageReadout::count_portage() {
-                packages.push((PackageManager::Portage, c));
-            }
+        if Path::new("/var/db/pkg").is_dir() {
+            counters.push((PackageManager::Portage, LinuxPackageReadout::count_portage));
         }
-        if extra::which("xbps-query") {
-            if let Some(c) = LinuxPackageReadout::count_xbps() {
-                packages.push((PackageManager::Xbps, c));
-            }
+        if Path::new("/var/db/xbps").is_dir() || extra::which("xbps-query") {
+            counters.push((PackageManager::Xbps, LinuxPackageReadout::count_xbps));
         }
-        if extra::which("rpm") {
-            if let Some(c) = LinuxPackageReadout::count_rpm() {
-                packages.push((PackageManager::Rpm, c));
-            }
+        if Path::new("/var/lib/rpm/rpmdb.sqlite").exists() || extra::which("rpm") {
+            counters.push((PackageManager::Rpm, LinuxPackageReadout::count_rpm));
         }
         if extra::which("eopkg") {
-            if let Some(c) = LinuxPackageReadout::count_eopkg() {
-                packages.push((PackageManager::Eopkg, c));
-            }
+            counters.push((PackageManager::Eopkg, LinuxPackageReadout::count_eopkg));
         }
-        if extra::which("apk") {
-            if let Some(c) = LinuxPackageReadout::count_apk() {
-                packages.push((PackageManager::Apk, c));
-            }
+        if Path::new("/lib/apk/db/installed").exists() {
+            counters.push((PackageManager::Apk, LinuxPackageReadout::count_apk));
         }
         if extra::which("cargo") {
-            if let Some(c) = LinuxPackageReadout::count_cargo() {
-                packages.push((PackageManager::Cargo, c));
-            }
+            counters.push((PackageManager::Cargo, LinuxPackageReadout::count_cargo));
         }
         if extra::which("flatpak") {
-            if let Some(c) = LinuxPackageReadout::count_flatpak() {
-                packages.push((PackageManager::Flatpak, c));
-            }
+            counters.push((PackageManager::Flatpak, LinuxPackageReadout::count_flatpak));
         }
-        if extra::which("snap") {
-            if let Some(c) = LinuxPackageReadout::count_snap() {
-                packages.push((PackageManager::Snap, c));
-            }
+        if extra::which("pip") || extra::which("python3") {
+            counters.push((PackageManager::Pip, LinuxPackageReadout::count_pip));
         }
-        if extra::which("brew") {
-            if let Some(c) = LinuxPackageReadout::count_homebrew() {
-                packages.push((PackageManager::Homebrew, c));
-            }
+        if Path::new("/nix").exists() {
+            counters.push((PackageManager::Nix, LinuxPackageReadout::count_nix));
+        }
+        if Path::new("/snap").exists() || extra::which("snap") {
+            counters.push((PackageManager::Snap, LinuxPackageReadout::count_snap));
+        }
+        if !crate::shared::homebrew_prefixes().is_empty() {
+            counters.push((
+                PackageManager::Homebrew,
+                LinuxPackageReadout::count_homebrew,
+            ));
         }
 
-        packages
+        if !crate::shared::appimage_dirs().is_empty() {
+            counters.push((
+                PackageManager::AppImage,
+                LinuxPackageReadout::count_appimage,
+            ));
+        }
+
+        crate::shared::count_packages(counters)
     }
 }
 
 impl LinuxPackageReadout {
     /// Returns the number of installed packages for systems
     /// that utilize `rpm` as their package manager.
+    ///
+    /// The rows of the `Packages` table of `/var/lib/rpm/rpmdb.sqlite` are counted
+    /// when the database uses the sqlite backend, which is the default since rpm 4.16.
+    /// Older Berkeley DB databases can only be read by rpm itself, in which case the
+    /// output of `rpm -qa` is counted instead.
     fn count_rpm() -> Option<usize> {
-        // Return the number of installed packages using sqlite (~1ms)
-        // as directly calling rpm or dnf is too expensive (~500ms)
-        let path = "/var/lib/rpm/rpmdb.sqlite";
-        let connection = sqlite::open(path);
-        if let Ok(con) = connection {
-            let statement = con.prepare("SELECT COUNT(*) FROM Installtid");
-            if let Ok(mut s) = statement {
-                if s.next().is_ok() {
-                    return match s.read::<Option<i64>>(0) {
-                        Ok(Some(count)) => Some(count as usize),
-                        _ => None,
-                    };
+        // Reading the database directly (~1ms) is much cheaper
+        // than calling rpm or dnf (~500ms).
+        // The database is only opened if it exists, as opening it would create it otherwise.
+        let rpmdb = Path::new("/var/lib/rpm/rpmdb.sqlite");
+        if rpmdb.exists() {
+            if let Ok(con) = sqlite::open(rpmdb) {
+                let statement = con.prepare("SELECT COUNT(*) FROM Packages");
+                if let Ok(mut s) = statement {
+                    if s.next().is_ok() {
+                        if let Ok(Some(count)) = s.read::<Option<i64>>(0) {
+                            return Some(count as usize);
+                        }
+                    }
                 }
             }
         }
 
-        None
+        if !extra::which("rpm") {
+            return None;
+        }
+
+        let rpm_output = crate::shared::run_with_timeout(
+            Command::new("rpm").arg("-qa"),
+            crate::shared::COMMAND_TIMEOUT,
+        )?;
+
+        if !rpm_output.status.success() {
+            return None;
+        }
+
+        extra::count_lines(String::from_utf8(rpm_output.stdout).ok()?)
     }
 
     /// Returns the number of installed packages for systems
     /// that utilize `pacman` as their package manager.
     fn count_pacman() -> Option<usize> {
-        let pacman_dir = Path::new("/var/lib/pacman/local");
-        if pacman_dir.exists() {
-            if let Ok(read_dir) = read_dir(pacman_dir) {
-                return Some(read_dir.count());
-            };
-        }
-
-        None
+        crate::shared::count_pacman(Path::new("/var/lib/pacman/local"))
     }
 
     /// Returns the number of installed packages for systems
@@ -686,56 +905,59 @@ impl LinuxPackageReadout {
     /// Returns the number of installed packages for systems
     /// that have `homebrew` installed.
     fn count_homebrew() -> Option<usize> {
-        if let Ok(home_dir) = std::env::var("HOME") {
-            let mut base = PathBuf::from(home_dir).join(".linuxbrew");
-            if !base.exists() {
-                base = PathBuf::from("/home/linuxbrew/.linuxbrew");
-            }
-
-            match read_dir(base.join("Cellar")) {
-                // subtract 1 as $base/Cellar contains a .keepme file
-                Ok(dir) => return Some(dir.count() - 1),
-                Err(_) => return None,
-            };
-        }
-
-        None
+        crate::shared::count_homebrew(&crate::shared::homebrew_prefixes())
     }
 
     /// Returns the number of installed packages for systems
     /// that utilize `portage` as their package manager.
     fn count_portage() -> Option<usize> {
-        Some(walkdir::WalkDir::new("/var/db/pkg").into_iter().count())
+        crate::shared::count_portage(Path::new("/var/db/pkg"))
     }
 
     /// Returns the number of installed packages for systems
     /// that utilize `xbps` as their package manager.
+    ///
+    /// The package database is read directly if it can be found, otherwise the
+    /// output of `xbps-query -l` is counted.
     fn count_xbps() -> Option<usize> {
-        let xbps_output = Command::new("xbps-query")
-            .arg("-l")
-            .stdout(Stdio::piped())
-            .output()
-            .unwrap();
+        let pkgdb = Path::new("/var/db/xbps/pkgdb-0.38.plist");
+        if let Some(count) = crate::shared::count_xbps(pkgdb) {
+            return Some(count);
+        }
+
+        if !extra::which("xbps-query") {
+            return None;
+        }
+
+        let xbps_output = crate::shared::run_with_timeout(
+            Command::new("xbps-query").arg("-l"),
+            crate::shared::COMMAND_TIMEOUT,
+        )?;
+
+        if !xbps_output.status.success() {
+            return None;
+        }
 
-        extra::count_lines(
-            String::from_utf8(xbps_output.stdout)
-                .expect("ERROR: \"xbps-query -l\" output was not valid UTF-8"),
-        )
+        extra::count_lines(String::from_utf8(xbps_output.stdout).ok()?)
     }
 
     /// Returns the number of installed packages for systems
     /// that utilize `apk` as their package manager.
+    ///
+    /// Every package in `/lib/apk/db/installed` is described by a block of
+    /// `<key>:<value>` lines, of which exactly one is its `P:` (name) line.
     fn count_apk() -> Option<usize> {
-        let apk_output = Command::new("apk")
-            .arg("info")
-            .stdout(Stdio::piped())
-            .output()
-            .unwrap();
+        let installed = fs::read_to_string("/lib/apk/db/installed").ok()?;
+        let count = installed
+            .lines()
+            .filter(|line| line.starts_with("P:"))
+            .count();
+
+        if count != 0 {
+            return Some(count);
+        }
 
-        extra::count_lines(
-            String::from_utf8(apk_output.stdout)
-                .expect("ERROR: \"apk info\" output was not valid UTF-8"),
-        )
+        None
     }
 
     /// Returns the number of installed packages for systems
@@ -744,56 +966,340 @@ impl LinuxPackageReadout {
         crate::shared::count_cargo()
     }
 
+    /// Returns the number of AppImages found in the directories listed by
+    /// `shared::appimage_dirs`.
+    fn count_appimage() -> Option<usize> {
+        crate::shared::count_appimages(&crate::shared::appimage_dirs())
+    }
+
     /// Returns the number of installed packages for systems
     /// that have `flatpak` installed.
     fn count_flatpak() -> Option<usize> {
-        // Return the number of system-wide installed flatpaks
-        let global_flatpak_dir = Path::new("/var/lib/flatpak/app");
-        let mut global_packages = 0;
-        if let Ok(dir) = read_dir(global_flatpak_dir) {
-            global_packages = dir.count();
-        }
-
-        // Return the number of per-user installed flatpaks
-        let mut user_packages: usize = 0;
-        if let Some(home_dir) = dirs::home_dir() {
-            let user_flatpak_dir = home_dir.join(".local/share/flatpak/app");
-            if let Ok(dir) = read_dir(user_flatpak_dir) {
-                user_packages = dir.count();
-            }
-        }
+        crate::shared::count_flatpak()
+    }
 
-        let total = global_packages + user_packages;
-        if total > 0 {
-            return Some(total);
-        }
+    /// Returns the number of installed packages for systems
+    /// that have `pip` installed.
+    fn count_pip() -> Option<usize> {
+        crate::shared::count_pip()
+    }
 
-        None
+    /// Returns the number of installed packages for systems
+    /// that have `nix` installed.
+    fn count_nix() -> Option<usize> {
+        crate::shared::count_nix()
     }
 
     /// Returns the number of installed packages for systems
     /// that have `snap` installed.
     fn count_snap() -> Option<usize> {
-        let snap_dir = Path::new("/var/lib/snapd/snaps");
-        if snap_dir.is_dir() {
-            let dir_entries = extra::list_dir_entries(snap_dir);
-            if !dir_entries.is_empty() {
-                return Some(
-                    dir_entries
-                        .iter()
-                        .filter(|x| {
-                            if let Some(ext) = extra::path_extension(x) {
-                                ext == "snap"
-                            } else {
-                                false
+        crate::shared::count_snaps(Path::new("/snap"), Path::new("/var/lib/snapd/snaps"), false)
+    }
+}
+
+impl GpuReadout for LinuxGpuReadout {
+    fn new() -> Self {
+        LinuxGpuReadout
+    }
+
+    fn model(&self) -> Result<Vec<String>, ReadoutError> {
+        let mut gpus: Vec<String> = LinuxGpuReadout::named_cards()
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect();
+
+        if gpus.is_empty() && extra::which("lspci") {
+            gpus = LinuxGpuReadout::lspci_models();
+        }
+
+        if gpus.is_empty() {
+            return Err(ReadoutError::Other(String::from(
+                "Could not detect any GPU on this system.",
+            )));
+        }
+
+        Ok(gpus)
+    }
+
+    fn vram_total(&self) -> Result<Vec<u64>, ReadoutError> {
+        // nvidia-smi lists the GPUs in the order of their PCI bus IDs, as do the DRM cards.
+        // nvidia-smi reports the memory in MiB.
+        let mut nvidia = LinuxGpuReadout::nvidia_smi_query("memory.total")
+            .into_iter()
+            .map(|mib| mib * 1024 * 1024);
+        let cards = LinuxGpuReadout::named_cards();
+
+        // model() falls back to lspci when sysfs is unavailable,
+        // in which case only NVIDIA GPUs can be reported.
+        if cards.is_empty() {
+            return Ok(nvidia.collect());
+        }
+
+        Ok(cards
+            .iter()
+            .map(|(card, _)| {
+                // Exposed by the amdgpu driver.
+                if let Ok(vram) =
+                    crate::shared::read_sysfs_u64(&card.join("device/mem_info_vram_total"))
+                {
+                    return vram;
+                }
+
+                match fs::read_to_string(card.join("device/vendor")) {
+                    Ok(vendor) if vendor.trim() == "0x10de" => nvidia.next().unwrap_or(0),
+                    _ => 0,
+                }
+            })
+            .collect())
+    }
+}
+
+impl LinuxGpuReadout {
+    /// Returns the kernel driver bound to every GPU in the order of `model()`.
+    fn drivers() -> Result<Vec<String>, ReadoutError> {
+        let cards = LinuxGpuReadout::named_cards();
+
+        // The drivers are only known for the GPUs found in sysfs.
+        if cards.is_empty() {
+            return Err(ReadoutError::MetricNotAvailable);
+        }
+
+        Ok(cards
+            .iter()
+            .map(|(card, _)| {
+                // device/driver links to /sys/bus/pci/drivers/<driver>.
+                fs::read_link(card.join("device/driver"))
+                    .ok()
+                    .and_then(|driver| Some(driver.file_name()?.to_string_lossy().into_owned()))
+                    .unwrap_or_default()
+            })
+            .collect())
+    }
+
+    /// Returns the temperature of every GPU in the order of `model()`, read from the card's
+    /// hwmon sensor or, for NVIDIA GPUs, from `nvidia-smi`.
+    fn temperatures() -> Vec<Result<f32, ReadoutError>> {
+        let mut nvidia = LinuxGpuReadout::nvidia_smi_query("temperature.gpu")
+            .into_iter()
+            .map(|celsius| celsius as f32);
+        let cards = LinuxGpuReadout::named_cards();
+
+        // Like vram_total(), only NVIDIA GPUs can be reported without sysfs.
+        if cards.is_empty() {
+            return nvidia.map(Ok).collect();
+        }
+
+        cards
+            .iter()
+            .map(|(card, _)| {
+                if let Some(temperature) = LinuxGpuReadout::hwmon_temperature(card) {
+                    return Ok(temperature);
+                }
+
+                match fs::read_to_string(card.join("device/vendor")) {
+                    Ok(vendor) if vendor.trim() == "0x10de" => {
+                        nvidia.next().ok_or(ReadoutError::MetricNotAvailable)
+                    }
+                    _ => Err(ReadoutError::MetricNotAvailable),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the `/sys/class/drm/cardN` entries of the GPUs whose name could be resolved,
+    /// along with that name.
+    fn named_cards() -> Vec<(PathBuf, String)> {
+        let mut gpus = Vec::new();
+
+        // Every GPU exposes its PCI vendor and device ID through
+        // /sys/class/drm/cardN/device, which can be resolved to a
+        // human-readable name using the system's pci.ids database.
+        for card in LinuxGpuReadout::drm_cards() {
+            let vendor = fs::read_to_string(card.join("device/vendor"));
+            let device = fs::read_to_string(card.join("device/device"));
+
+            if let (Ok(vendor), Ok(device)) = (vendor, device) {
+                let vendor = extra::pop_newline(vendor)
+                    .trim_start_matches("0x")
+                    .to_lowercase();
+                let device = extra::pop_newline(device)
+                    .trim_start_matches("0x")
+                    .to_lowercase();
+
+                if let Some(name) = LinuxGpuReadout::pci_ids_lookup(&vendor, &device) {
+                    gpus.push((card, name));
+                }
+            }
+        }
+
+        gpus
+    }
+
+    /// Returns the value of the given `nvidia-smi --query-gpu` field for every NVIDIA GPU,
+    /// since the proprietary driver doesn't expose its metrics through sysfs.
+    fn nvidia_smi_query(field: &str) -> Vec<u64> {
+        if !extra::which("nvidia-smi") {
+            return Vec::new();
+        }
+
+        let output = crate::shared::run_with_timeout(
+            Command::new("nvidia-smi").args([
+                &format!("--query-gpu={}", field),
+                "--format=csv,noheader,nounits",
+            ]),
+            crate::shared::COMMAND_TIMEOUT,
+        );
+
+        match output {
+            Some(output) => String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(|line| line.trim().parse::<u64>().ok())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the temperature in °C reported by the first hwmon sensor of the given
+    /// `/sys/class/drm/cardN` entry, as exposed by _e.g._ the amdgpu and nouveau drivers.
+    fn hwmon_temperature(card: &Path) -> Option<f32> {
+        let mut sensors = list_dir_entries(&card.join("device/hwmon"));
+        sensors.sort();
+
+        // temp1_input is given in millidegrees Celsius.
+        sensors.iter().find_map(|sensor| {
+            crate::shared::read_sysfs_string(&sensor.join("temp1_input"))
+                .ok()?
+                .parse::<i64>()
+                .ok()
+                .map(|millidegrees| millidegrees as f32 / 1000.0)
+        })
+    }
+
+    /// Returns the paths of all `/sys/class/drm/cardN` entries, skipping
+    /// the connectors (e.g. `card0-eDP-1`) that live in the same directory.
+    fn drm_cards() -> Vec<PathBuf> {
+        let mut cards: Vec<PathBuf> = extra::list_dir_entries(Path::new("/sys/class/drm"))
+            .into_iter()
+            .filter(|path| {
+                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+                    name.starts_with("card") && !name.contains('-')
+                } else {
+                    false
+                }
+            })
+            .collect();
+
+        cards.sort();
+        cards
+    }
+
+    /// Resolves a PCI vendor and device ID pair (lowercase hexadecimal, without
+    /// the `0x` prefix) to a name using the `pci.ids` database shipped by most
+    /// distributions.
+    fn pci_ids_lookup(vendor: &str, device: &str) -> Option<String> {
+        let databases = [
+            "/usr/share/hwdata/pci.ids",
+            "/usr/share/misc/pci.ids",
+            "/usr/share/pci.ids",
+        ];
+
+        for database in databases.iter() {
+            if let Ok(lines) = extra::read_lines(database) {
+                let mut vendor_name: Option<String> = None;
+
+                for line in lines.map_while(Result::ok) {
+                    if let Some(vendor_name) = &vendor_name {
+                        // Devices are indented by one tab, subsystems by two.
+                        if !line.starts_with('\t') && !line.starts_with('#') && !line.is_empty() {
+                            break;
+                        }
+
+                        if !line.starts_with("\t\t") {
+                            let entry = line.trim_start();
+                            if entry.to_lowercase().starts_with(device) {
+                                let device_name = entry[device.len()..].trim();
+                                return Some(format!("{} {}", vendor_name, device_name));
                             }
-                        })
-                        .into_iter()
-                        .count(),
-                );
+                        }
+                    } else if line.to_lowercase().starts_with(vendor) {
+                        vendor_name = Some(line[vendor.len()..].trim().to_string());
+                    }
+                }
+
+                if let Some(vendor_name) = vendor_name {
+                    return Some(vendor_name);
+                }
             }
         }
 
         None
     }
+
+    /// Returns the GPU names reported by `lspci -mm`, used when sysfs or
+    /// the `pci.ids` database is unavailable.
+    fn lspci_models() -> Vec<String> {
+        let mut gpus = Vec::new();
+
+        if let Some(output) = crate::shared::run_with_timeout(
+            Command::new("lspci").arg("-mm"),
+            crate::shared::COMMAND_TIMEOUT,
+        ) {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+
+            // e.g. 00:02.0 "VGA compatible controller" "Intel Corporation" "UHD Graphics 620" ...
+            for line in stdout.lines() {
+                let fields: Vec<&str> = line.split('"').collect();
+                if fields.len() < 6 {
+                    continue;
+                }
+
+                let class = fields[1];
+                if class.contains("VGA") || class.contains("3D") || class.contains("Display") {
+                    gpus.push(format!("{} {}", fields[3], fields[5]));
+                }
+            }
+        }
+
+        gpus
+    }
+}
+
+// The statvfs fields are not 64 bits wide on every architecture.
+#[allow(clippy::unnecessary_cast)]
+impl DiskReadout for LinuxDiskReadout {
+    fn new() -> Self {
+        LinuxDiskReadout
+    }
+
+    fn total(&self, path: &Path) -> Result<u64, ReadoutError> {
+        let stats = crate::shared::statvfs(path)?;
+        Ok(stats.f_blocks as u64 * stats.f_frsize as u64)
+    }
+
+    fn free(&self, path: &Path) -> Result<u64, ReadoutError> {
+        let stats = crate::shared::statvfs(path)?;
+        Ok(stats.f_bavail as u64 * stats.f_frsize as u64)
+    }
+}
+
+impl NetworkReadout for LinuxNetworkReadout {
+    fn new() -> Self {
+        LinuxNetworkReadout
+    }
+
+    fn tx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::network_statistic(interface, "tx_bytes")
+    }
+
+    fn rx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::network_statistic(interface, "rx_bytes")
+    }
+
+    fn mac_address(&self, interface: Option<String>) -> Result<String, ReadoutError> {
+        crate::shared::mac_address(interface)
+    }
+
+    fn link_speed(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::link_speed(interface)
+    }
 }
@@ -14,6 +14,7 @@ use core_foundation::string::CFString;
 use core_graphics::display::CGDisplay;
 use mach::kern_return::KERN_SUCCESS;
 use std::ffi::CString;
+use std::net::IpAddr;
 use sysctl::{Ctl, Sysctl};
 
 mod mach_ffi;
@@ -52,6 +53,12 @@ struct MacOSIOPMPowerSource {
 
 pub struct MacOSPackageReadout;
 
+pub struct MacOSGpuReadout;
+
+pub struct MacOSDiskReadout;
+
+pub struct MacOSNetworkReadout;
+
 impl BatteryReadout for MacOSBatteryReadout {
     fn new() -> Self {
         MacOSBatteryReadout {
@@ -190,6 +197,10 @@ impl KernelReadout for MacOSKernelReadout {
     fn pretty_kernel(&self) -> Result<String, ReadoutError> {
         Ok(format!("{} {}", self.os_type()?, self.os_release()?))
     }
+
+    fn architecture(&self) -> Result<String, ReadoutError> {
+        crate::shared::architecture()
+    }
 }
 
 impl GeneralReadout for MacOSGeneralReadout {
@@ -202,7 +213,7 @@ impl GeneralReadout for MacOSGeneralReadout {
         }
     }
 
-    fn resolution(&self) -> Result<String, ReadoutError> {
+    fn resolution(&self) -> Result<Vec<String>, ReadoutError> {
         let displays = CGDisplay::active_displays();
         if let Err(e) = displays {
             return Err(ReadoutError::Other(format!(
@@ -241,7 +252,15 @@ impl GeneralReadout for MacOSGeneralReadout {
             output.push(out_string);
         }
 
-        Ok(output.join("\n"))
+        Ok(output)
+    }
+
+    fn locale(&self) -> Result<String, ReadoutError> {
+        crate::shared::locale()
+    }
+
+    fn timezone(&self) -> Result<String, ReadoutError> {
+        crate::shared::timezone()
     }
 
     fn username(&self) -> Result<String, ReadoutError> {
@@ -266,6 +285,10 @@ impl GeneralReadout for MacOSGeneralReadout {
         crate::shared::local_ip(interface)
     }
 
+    fn local_ips(&self, interface: Option<String>) -> Result<Vec<IpAddr>, ReadoutError> {
+        crate::shared::local_ips(interface)
+    }
+
     fn desktop_environment(&self) -> Result<String, ReadoutError> {
         Ok(String::from("Aqua"))
     }
@@ -315,6 +338,10 @@ impl GeneralReadout for MacOSGeneralReadout {
             .value_string()?)
     }
 
+    fn load_average(&self) -> Result<(f64, f64, f64), ReadoutError> {
+        crate::shared::load_average()
+    }
+
     fn cpu_usage(&self) -> Result<usize, ReadoutError> {
         crate::shared::cpu_usage()
     }
@@ -521,7 +548,7 @@ impl PackageReadout for MacOSPackageReadout {
 
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
         let mut packages = Vec::new();
-        if extra::which("brew") {
+        if !crate::shared::homebrew_prefixes().is_empty() {
             match MacOSPackageReadout::count_homebrew() {
                 Some(c) => packages.push((PackageManager::Homebrew, c)),
                 _ => (),
@@ -539,45 +566,30 @@ impl PackageReadout for MacOSPackageReadout {
     }
 }
 
-impl MacOSPackageReadout {
-    /// This method returns the total entries of `/usr/local/Cellar` and `/usr/local/Caskroom` directories
-    /// which contain all installed packages of the Homebrew package manager.
-    /// A manual call via `homebrew list` would be too expensive, since it is pretty slow.
-    fn count_homebrew() -> Option<usize> {
-        use std::fs::read_dir;
-        use std::path::Path;
-
-        // Homebrew stores packages in /usr/local on older-generation Apple hardware.
-        let homebrew_root = Path::new("/usr/local");
-        let cellar_folder = homebrew_root.join("Cellar");
-        let caskroom_folder = homebrew_root.join("Caskroom");
-
-        let cellar_count = match read_dir(cellar_folder) {
-            Ok(read_dir) => read_dir.count(),
-            Err(_) => 0,
-        };
-
-        let caskroom_count = match read_dir(caskroom_folder) {
-            Ok(read_dir) => read_dir.count(),
-            Err(_) => 0,
-        };
-
-        // Homebrew stores packages in /opt/homebrew on Apple Silicon machines.
-        let opt_homebrew_root = Path::new("/opt/homebrew");
-        let opt_cellar_folder = opt_homebrew_root.join("Cellar");
-        let opt_caskroom_folder = opt_homebrew_root.join("Caskroom");
+impl GpuReadout for MacOSGpuReadout {
+    fn new() -> Self {
+        MacOSGpuReadout
+    }
+}
 
-        let opt_cellar_count = match read_dir(opt_cellar_folder) {
-            Ok(read_dir) => read_dir.count(),
-            Err(_) => 0,
-        };
+impl DiskReadout for MacOSDiskReadout {
+    fn new() -> Self {
+        MacOSDiskReadout
+    }
+}
 
-        let opt_caskroom_count = match read_dir(opt_caskroom_folder) {
-            Ok(read_dir) => read_dir.count(),
-            Err(_) => 0,
-        };
+impl NetworkReadout for MacOSNetworkReadout {
+    fn new() -> Self {
+        MacOSNetworkReadout
+    }
+}
 
-        Some(cellar_count + caskroom_count + opt_cellar_count + opt_caskroom_count)
+impl MacOSPackageReadout {
+    /// This method returns the total entries of the `Cellar` and `Caskroom` directories
+    /// of the Homebrew prefix, which contain all installed packages of the Homebrew package manager.
+    /// A manual call via `homebrew list` would be too expensive, since it is pretty slow.
+    fn count_homebrew() -> Option<usize> {
+        crate::shared::count_homebrew(&crate::shared::homebrew_prefixes())
     }
 
     fn count_cargo() -> Option<usize> {
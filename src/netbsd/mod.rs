@@ -7,8 +7,8 @@ use nix::unistd;
 use regex::Regex;
 use std::ffi::CString;
 use std::fs;
-use std::fs::read_dir;
-use std::path::PathBuf;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
 pub struct NetBSDBatteryReadout;
@@ -23,6 +23,12 @@ pub struct NetBSDProductReadout;
 
 pub struct NetBSDPackageReadout;
 
+pub struct NetBSDGpuReadout;
+
+pub struct NetBSDDiskReadout;
+
+pub struct NetBSDNetworkReadout;
+
 impl BatteryReadout for NetBSDBatteryReadout {
     fn new() -> Self {
         NetBSDBatteryReadout
@@ -123,6 +129,10 @@ impl KernelReadout for NetBSDKernelReadout {
             "This information is provided by the OperatingSystem readout on NetBSD.",
         )))
     }
+
+    fn architecture(&self) -> Result<String, ReadoutError> {
+        crate::shared::architecture()
+    }
 }
 
 impl GeneralReadout for NetBSDGeneralReadout {
@@ -130,7 +140,7 @@ impl GeneralReadout for NetBSDGeneralReadout {
         NetBSDGeneralReadout
     }
 
-    fn resolution(&self) -> Result<String, ReadoutError> {
+    fn resolution(&self) -> Result<Vec<String>, ReadoutError> {
         Err(ReadoutError::MetricNotAvailable)
     }
 
@@ -185,6 +195,18 @@ impl GeneralReadout for NetBSDGeneralReadout {
         crate::shared::local_ip(interface)
     }
 
+    fn local_ips(&self, interface: Option<String>) -> Result<Vec<IpAddr>, ReadoutError> {
+        crate::shared::local_ips(interface)
+    }
+
+    fn locale(&self) -> Result<String, ReadoutError> {
+        crate::shared::locale()
+    }
+
+    fn timezone(&self) -> Result<String, ReadoutError> {
+        crate::shared::timezone()
+    }
+
     fn username(&self) -> Result<String, ReadoutError> {
         crate::shared::username()
     }
@@ -217,6 +239,22 @@ impl GeneralReadout for NetBSDGeneralReadout {
         crate::shared::session()
     }
 
+    fn session_type(&self) -> Result<String, ReadoutError> {
+        crate::shared::session_type()
+    }
+
+    fn theme(&self) -> Result<String, ReadoutError> {
+        crate::shared::theme()
+    }
+
+    fn icons(&self) -> Result<String, ReadoutError> {
+        crate::shared::icons()
+    }
+
+    fn accent_color(&self) -> Result<(u8, u8, u8), ReadoutError> {
+        crate::shared::accent_color()
+    }
+
     fn window_manager(&self) -> Result<String, ReadoutError> {
         crate::winman::detect_xorg_window_manager()
     }
@@ -302,6 +340,10 @@ impl GeneralReadout for NetBSDGeneralReadout {
         crate::shared::cpu_physical_cores()
     }
 
+    fn load_average(&self) -> Result<(f64, f64, f64), ReadoutError> {
+        crate::shared::load_average()
+    }
+
     fn cpu_usage(&self) -> Result<usize, ReadoutError> {
         crate::shared::cpu_usage()
     }
@@ -353,16 +395,16 @@ impl MemoryReadout for NetBSDMemoryReadout {
     }
 
     fn total(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("MemTotal"))
+        crate::shared::get_meminfo_value("MemTotal")
     }
 
     fn free(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("MemFree"))
+        crate::shared::get_meminfo_value("MemFree")
     }
 
     fn used(&self) -> Result<u64, ReadoutError> {
-        let total = self.total().unwrap();
-        let free = self.free().unwrap();
+        let total = self.total()?;
+        let free = self.free()?;
 
         Ok(total - free)
     }
@@ -420,8 +462,8 @@ impl PackageReadout for NetBSDPackageReadout {
         // Instead of having a condition for each distribution.
         // we will try and extract package count by checking
         // if a certain package manager is installed
-        if extra::which("pkgin") {
-            match NetBSDPackageReadout::count_pkgin() {
+        if let Some(pkg_dbdir) = NetBSDPackageReadout::pkg_dbdir() {
+            match NetBSDPackageReadout::count_pkgin(&pkg_dbdir) {
                 Some(c) => packages.push((PackageManager::Pkgsrc, c)),
                 _ => (),
             }
@@ -438,18 +480,51 @@ impl PackageReadout for NetBSDPackageReadout {
     }
 }
 
-impl NetBSDPackageReadout {
-    fn count_pkgin() -> Option<usize> {
-        if let Some(pkg_dbdir) = dirs::pkgdb_dir() {
-            if let Ok(read_dir) = read_dir(pkg_dbdir) {
-                return Some(read_dir.count() - 1);
-            };
-        }
+impl GpuReadout for NetBSDGpuReadout {
+    fn new() -> Self {
+        NetBSDGpuReadout
+    }
+}
 
-        if let Some(localbase_dir) = dirs::localbase_dir() {
-            if let Ok(read_dir) = read_dir(localbase_dir.join("pkgdb")) {
-                return Some(read_dir.count() - 1);
-            }
+impl DiskReadout for NetBSDDiskReadout {
+    fn new() -> Self {
+        NetBSDDiskReadout
+    }
+}
+
+impl NetworkReadout for NetBSDNetworkReadout {
+    fn new() -> Self {
+        NetBSDNetworkReadout
+    }
+}
+
+impl NetBSDPackageReadout {
+    /// Returns the first pkgsrc package database that exists, _e.g._ `/usr/pkg/pkgdb`.
+    fn pkg_dbdir() -> Option<PathBuf> {
+        let pkg_dbdirs = [
+            dirs::pkgdb_dir(),
+            dirs::localbase_dir().map(|localbase| localbase.join("pkgdb")),
+            Some(PathBuf::from("/var/db/pkg")),
+        ];
+
+        pkg_dbdirs
+            .iter()
+            .flatten()
+            .find(|pkg_dbdir| pkg_dbdir.is_dir())
+            .cloned()
+    }
+
+    /// Returns the number of installed pkgsrc packages, each of which is a
+    /// directory in the package database, next to files such as
+    /// `pkgdb.byfile.db` that aren't counted.
+    fn count_pkgin(pkg_dbdir: &Path) -> Option<usize> {
+        let packages = extra::list_dir_entries(pkg_dbdir)
+            .iter()
+            .filter(|entry| entry.is_dir())
+            .count();
+
+        if packages != 0 {
+            return Some(packages);
         }
 
         None
@@ -0,0 +1,72 @@
+//! Asynchronous wrappers around readouts that block, available with the `async` feature.
+//!
+//! Counting packages and sampling the CPU usage spawn processes or sleep, which would
+//! freeze the event loop of an asynchronous application. The extension traits in this
+//! module run that work on Tokio's blocking thread pool instead, leaving the synchronous
+//! API untouched.
+//!
+//! ```
+//! use libmacchina::nonblocking::PackageReadoutExt;
+//! use libmacchina::traits::PackageReadout as _;
+//! use libmacchina::PackageReadout;
+//!
+//! let runtime = tokio::runtime::Builder::new_current_thread()
+//!     .build()
+//!     .unwrap();
+//!
+//! for (manager, count) in runtime.block_on(PackageReadout::new().count_pkgs_async()) {
+//!     println!("{}: {}", manager.to_string(), count);
+//! }
+//! ```
+use crate::traits::{GeneralReadout, PackageManager, PackageReadout, ReadoutError};
+use std::future::Future;
+use std::pin::Pin;
+
+/// A boxed future, as returned by the methods of the extension traits.
+pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Runs `work` on the blocking thread pool, resuming its panic if it panicked.
+fn spawn_blocking<T, F>(work: F) -> BoxFuture<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    Box::pin(async move {
+        match tokio::task::spawn_blocking(work).await {
+            Ok(value) => value,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    })
+}
+
+/// Asynchronous variants of the blocking `PackageReadout` methods.
+pub trait PackageReadoutExt {
+    /// Counts the installed packages like `PackageReadout::count_pkgs()`, on a
+    /// separate blocking task that takes ownership of the readout.
+    fn count_pkgs_async(self) -> BoxFuture<Vec<(PackageManager, usize)>>;
+}
+
+impl<T> PackageReadoutExt for T
+where
+    T: PackageReadout + Send + 'static,
+{
+    fn count_pkgs_async(self) -> BoxFuture<Vec<(PackageManager, usize)>> {
+        spawn_blocking(move || self.count_pkgs())
+    }
+}
+
+/// Asynchronous variants of the blocking `GeneralReadout` methods.
+pub trait GeneralReadoutExt {
+    /// Samples the usage of each logical core like `GeneralReadout::cpu_usage_per_core()`,
+    /// on a separate blocking task that takes ownership of the readout.
+    fn cpu_usage_per_core_async(self) -> BoxFuture<Result<Vec<usize>, ReadoutError>>;
+}
+
+impl<T> GeneralReadoutExt for T
+where
+    T: GeneralReadout + Send + 'static,
+{
+    fn cpu_usage_per_core_async(self) -> BoxFuture<Result<Vec<usize>, ReadoutError>> {
+        spawn_blocking(move || self.cpu_usage_per_core())
+    }
+}
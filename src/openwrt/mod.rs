@@ -28,6 +28,12 @@ pub struct OpenWrtProductReadout;
 
 pub struct OpenWrtPackageReadout;
 
+pub struct OpenWrtGpuReadout;
+
+pub struct OpenWrtDiskReadout;
+
+pub struct OpenWrtNetworkReadout;
+
 impl BatteryReadout for OpenWrtBatteryReadout {
     fn new() -> Self {
         OpenWrtBatteryReadout
@@ -57,6 +63,10 @@ impl KernelReadout for OpenWrtKernelReadout {
             .ok_or(ReadoutError::MetricNotAvailable)?
             .value_string()?)
     }
+
+    fn architecture(&self) -> Result<String, ReadoutError> {
+        crate::shared::architecture()
+    }
 }
 
 impl GeneralReadout for OpenWrtGeneralReadout {
@@ -226,20 +236,24 @@ impl MemoryReadout for OpenWrtMemoryReadout {
         }
     }
 
+    fn available(&self) -> Result<u64, ReadoutError> {
+        crate::shared::get_meminfo_value("MemAvailable")
+    }
+
     fn cached(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("Cached"))
+        crate::shared::get_meminfo_value("Cached")
     }
 
     fn reclaimable(&self) -> Result<u64, ReadoutError> {
-        Ok(crate::shared::get_meminfo_value("SReclaimable"))
+        crate::shared::get_meminfo_value("SReclaimable")
     }
 
     fn used(&self) -> Result<u64, ReadoutError> {
-        let total = self.total().unwrap();
-        let free = self.free().unwrap();
-        let cached = self.cached().unwrap();
-        let reclaimable = self.reclaimable().unwrap();
-        let buffers = self.buffers().unwrap();
+        let total = self.total()?;
+        let free = self.free()?;
+        let cached = self.cached()?;
+        let reclaimable = self.reclaimable().unwrap_or(0);
+        let buffers = self.buffers()?;
 
         if reclaimable != 0 {
             return Ok(total - free - cached - reclaimable - buffers);
@@ -267,6 +281,38 @@ impl PackageReadout for OpenWrtPackageReadout {
     }
 }
 
+impl ProductReadout for OpenWrtProductReadout {
+    fn new() -> Self {
+        OpenWrtProductReadout
+    }
+}
+
+impl GpuReadout for OpenWrtGpuReadout {
+    fn new() -> Self {
+        OpenWrtGpuReadout
+    }
+}
+
+impl DiskReadout for OpenWrtDiskReadout {
+    fn new() -> Self {
+        OpenWrtDiskReadout
+    }
+}
+
+impl NetworkReadout for OpenWrtNetworkReadout {
+    fn new() -> Self {
+        OpenWrtNetworkReadout
+    }
+
+    fn tx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::network_statistic(interface, "tx_bytes")
+    }
+
+    fn rx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        crate::shared::network_statistic(interface, "rx_bytes")
+    }
+}
+
 impl OpenWrtPackageReadout {
     /// Returns the number of installed packages for systems
     /// that utilize `opkg` as their package manager. \
@@ -2,7 +2,7 @@
 #![allow(unused_imports)]
 
 use crate::extra;
-use crate::traits::{ReadoutError, ShellFormat, ShellKind};
+use crate::traits::{BatteryState, PackageManager, ReadoutError, ShellFormat, ShellKind};
 
 use std::fs::read_dir;
 use std::fs::read_to_string;
@@ -30,9 +30,92 @@ impl From<std::io::Error> for ReadoutError {
     }
 }
 
+/// Reads the file at `path`, reporting failures as `ReadoutError::FileRead`.
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+pub(crate) fn read_file<P: AsRef<Path>>(path: P) -> Result<String, ReadoutError> {
+    fs::read_to_string(&path).map_err(|source| ReadoutError::FileRead {
+        path: path.as_ref().to_path_buf(),
+        source,
+    })
+}
+
+/// Converts a field of `libc::utsname` into a `String`, stopping at its terminating
+/// NUL byte or at the end of the field, whichever comes first.
+///
+/// Fields that aren't valid UTF-8 are reported as an error instead of panicking.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+// `c_char` is `u8` on some architectures, such as aarch64.
+#[allow(clippy::unnecessary_cast)]
+pub(crate) fn utsname_field(field: &[libc::c_char]) -> Result<String, ReadoutError> {
+    let bytes: Vec<u8> = field
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as u8)
+        .collect();
+
+    String::from_utf8(bytes)
+        .map_err(|e| ReadoutError::Other(format!("Invalid UTF-8 in utsname: {}", e)))
+}
+
+/// Returns the `machine` field of `uname(2)`, _i.e._ the hardware architecture.
+#[cfg(not(any(target_os = "android", target_os = "windows")))]
+pub(crate) fn architecture() -> Result<String, ReadoutError> {
+    let mut utsname: libc::utsname = unsafe { std::mem::zeroed() };
+    if unsafe { libc::uname(&mut utsname) } == -1 {
+        return Err(ReadoutError::Other(String::from(
+            "Failed to get architecture",
+        )));
+    }
+
+    Ok(unsafe { CStr::from_ptr(utsname.machine.as_ptr()) }
+        .to_string_lossy()
+        .into_owned())
+}
+
+/// Returns the hostname, as reported by `gethostname(2)`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn hostname() -> Result<String, ReadoutError> {
+    // POSIX limits hostnames to 255 bytes, Linux to 64.
+    let host_name_max = match unsafe { libc::sysconf(libc::_SC_HOST_NAME_MAX) } {
+        max if max > 0 => (max as usize).min(255),
+        _ => 255,
+    };
+
+    let mut buffer = [0 as libc::c_char; 256];
+    if unsafe { libc::gethostname(buffer.as_mut_ptr(), host_name_max + 1) } == -1 {
+        return Err(ReadoutError::Other(String::from("Failed to get hostname")));
+    }
+
+    // gethostname() doesn't terminate truncated names.
+    buffer[host_name_max] = 0;
+    Ok(unsafe { CStr::from_ptr(buffer.as_ptr()) }
+        .to_string_lossy()
+        .into_owned())
+}
+
+/// Reads a single-value file, as found in sysfs and procfs, without its trailing whitespace.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn read_sysfs_string(path: &Path) -> Result<String, ReadoutError> {
+    Ok(read_file(path)?.trim_end().to_string())
+}
+
+/// Reads a single-value file, as found in sysfs and procfs, and parses it into a `u64`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn read_sysfs_u64(path: &Path) -> Result<u64, ReadoutError> {
+    let value = read_sysfs_string(path)?;
+    value.parse::<u64>().map_err(|e| {
+        ReadoutError::Other(format!(
+            "Could not parse the value '{}' of {} into a digit: {}",
+            value,
+            path.display(),
+            e
+        ))
+    })
+}
+
 #[cfg(not(any(target_os = "freebsd", target_os = "macos", target_os = "windows")))]
 pub(crate) fn uptime() -> Result<usize, ReadoutError> {
-    let uptime_file_text = fs::read_to_string("/proc/uptime")?;
+    let uptime_file_text = read_file("/proc/uptime")?;
     let uptime_text = uptime_file_text.split_whitespace().next().unwrap();
     let parsed_uptime = uptime_text.parse::<f64>();
 
@@ -45,6 +128,133 @@ pub(crate) fn uptime() -> Result<usize, ReadoutError> {
     }
 }
 
+/// Detects the sound server through its socket in `XDG_RUNTIME_DIR`, or otherwise its
+/// process in `/proc`, falling back to ALSA when the kernel provides sound cards.
+#[cfg(target_os = "linux")]
+pub(crate) fn audio_server() -> Result<String, ReadoutError> {
+    const SERVERS: [(Option<&str>, &str, &str); 3] = [
+        (Some("pipewire-0"), "pipewire", "PipeWire"),
+        (Some("pulse/native"), "pulseaudio", "PulseAudio"),
+        (None, "jackd", "JACK"),
+    ];
+
+    // Probing a couple of sockets is far cheaper than walking every process.
+    if let Some(runtime_dir) = env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from) {
+        for (socket, _, name) in SERVERS.iter() {
+            if matches!(socket, Some(socket) if runtime_dir.join(socket).exists()) {
+                return Ok(String::from(*name));
+            }
+        }
+    }
+
+    let processes: Vec<String> = match read_dir("/proc") {
+        Ok(entries) => entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| fs::read_to_string(entry.path().join("comm")).ok())
+            .map(|comm| comm.trim().to_string())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    for (_, process, name) in SERVERS.iter() {
+        if processes.iter().any(|comm| comm == process) {
+            return Ok(String::from(*name));
+        }
+    }
+
+    if Path::new("/proc/asound").exists() {
+        return Ok(String::from("ALSA"));
+    }
+
+    Err(ReadoutError::Other(String::from(
+        "No sound server or sound card detected.",
+    )))
+}
+
+/// Detects the container or virtual machine the OS is running in. Containers are
+/// checked first, as they usually run inside a virtual machine themselves.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn virtualization() -> Result<String, ReadoutError> {
+    if Path::new("/.dockerenv").exists() {
+        return Ok(String::from("docker"));
+    }
+
+    if Path::new("/run/.containerenv").exists() {
+        return Ok(String::from("podman"));
+    }
+
+    if let Ok(cgroup) = fs::read_to_string("/proc/1/cgroup") {
+        if cgroup.contains("/docker") {
+            return Ok(String::from("docker"));
+        } else if cgroup.contains("/lxc") {
+            return Ok(String::from("lxc"));
+        }
+    }
+
+    if let Ok(version) = fs::read_to_string("/proc/version") {
+        if version.to_lowercase().contains("microsoft") {
+            return Ok(String::from("wsl"));
+        }
+    }
+
+    let dmi = |field: &str| fs::read_to_string(Path::new("/sys/class/dmi/id").join(field));
+    if let (Ok(vendor), Ok(product)) = (dmi("sys_vendor"), dmi("product_name")) {
+        if let Some(hypervisor) = hypervisor_from_dmi(vendor.trim(), product.trim()) {
+            return Ok(String::from(hypervisor));
+        }
+    }
+
+    // The CPUID hypervisor bit is set by every hypervisor, but doesn't tell which one it is.
+    if let Ok(cpuinfo) = fs::read_to_string("/proc/cpuinfo") {
+        let is_guest = cpuinfo
+            .lines()
+            .filter(|line| line.starts_with("flags"))
+            .any(|flags| flags.split_whitespace().any(|flag| flag == "hypervisor"));
+
+        if is_guest {
+            return Ok(String::from("vm-other"));
+        }
+    }
+
+    Ok(String::from("none"))
+}
+
+/// Maps the DMI system vendor and product name to the identifier of the hypervisor,
+/// as used by `systemd-detect-virt`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn hypervisor_from_dmi(vendor: &str, product: &str) -> Option<&'static str> {
+    const HYPERVISORS: [(&str, &str); 8] = [
+        ("KVM", "kvm"),
+        ("QEMU", "qemu"),
+        ("VMware", "vmware"),
+        ("VirtualBox", "oracle"),
+        ("innotek GmbH", "oracle"),
+        ("Xen", "xen"),
+        ("Parallels", "parallels"),
+        ("Amazon EC2", "amazon"),
+    ];
+
+    if vendor == "Microsoft Corporation" && product == "Virtual Machine" {
+        return Some("microsoft");
+    }
+
+    HYPERVISORS
+        .iter()
+        .find(|(name, _)| vendor.starts_with(name) || product.starts_with(name))
+        .map(|(_, hypervisor)| *hypervisor)
+}
+
+/// Returns the boot time in seconds since the UNIX epoch, as found in the `btime`
+/// line of `/proc/stat`.
+#[cfg(target_os = "linux")]
+pub(crate) fn boot_time() -> Result<u64, ReadoutError> {
+    let stat = read_file("/proc/stat")?;
+    stat.lines()
+        .find_map(|line| line.strip_prefix("btime "))
+        .and_then(|btime| btime.trim().parse::<u64>().ok())
+        .ok_or_else(|| ReadoutError::Other(String::from("Could not find btime in /proc/stat")))
+}
+
 #[cfg(not(any(
     feature = "openwrt",
     target_os = "android",
@@ -61,14 +271,96 @@ pub(crate) fn desktop_environment() -> Result<String, ReadoutError> {
                 )));
             }
 
-            Ok(extra::ucfirst(de))
+            Ok(normalize_desktop_environment(&de))
         }
         Err(_) => Err(ReadoutError::Other(String::from(
-            "You appear to be only running a window manager.",
+            "No desktop environment detected",
         ))),
     }
 }
 
+/// Maps the value of `XDG_CURRENT_DESKTOP` or `DESKTOP_SESSION` to the
+/// commonly used name of the desktop environment.
+///
+/// `XDG_CURRENT_DESKTOP` can hold a colon-separated list, e.g. `ubuntu:GNOME`,
+/// in which case the first known desktop environment is picked.
+#[cfg(not(any(
+    feature = "openwrt",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+fn normalize_desktop_environment(de: &str) -> String {
+    fn known_name(de: &str) -> Option<&'static str> {
+        match de.to_lowercase().as_str() {
+            "gnome" | "gnome-xorg" | "gnome-classic" => Some("GNOME"),
+            "kde" | "plasma" | "plasmawayland" => Some("KDE"),
+            "xfce" | "xfce4" => Some("XFCE"),
+            "x-cinnamon" | "cinnamon" => Some("Cinnamon"),
+            "mate" => Some("MATE"),
+            "lxde" => Some("LXDE"),
+            "lxqt" => Some("LXQt"),
+            "budgie" | "budgie-desktop" => Some("Budgie"),
+            "unity" => Some("Unity"),
+            "pantheon" => Some("Pantheon"),
+            "deepin" => Some("Deepin"),
+            "enlightenment" => Some("Enlightenment"),
+            _ => None,
+        }
+    }
+
+    if let Some(name) = de.split(':').find_map(known_name) {
+        return String::from(name);
+    }
+
+    extra::ucfirst(de.split(':').next().unwrap_or(de))
+}
+
+/// Returns the version of the running desktop environment, as reported by its
+/// `--version` flag, _e.g._ `45.2` on GNOME.
+///
+/// Plasma falls back to `KDE_SESSION_VERSION`, which only holds the major version.
+#[cfg(all(target_os = "linux", not(feature = "openwrt")))]
+pub(crate) fn desktop_environment_version() -> Result<String, ReadoutError> {
+    let desktop_environment = desktop_environment()?;
+    let command = match desktop_environment.as_str() {
+        "GNOME" => "gnome-shell",
+        "KDE" => "plasmashell",
+        "XFCE" => "xfce4-session",
+        _ => return Err(ReadoutError::MetricNotAvailable),
+    };
+
+    let version = run_with_timeout(
+        Command::new(command).arg("--version").stderr(Stdio::null()),
+        COMMAND_TIMEOUT,
+    )
+    .filter(|output| output.status.success())
+    .and_then(|output| parse_version_output(&String::from_utf8_lossy(&output.stdout)));
+
+    match version {
+        Some(version) => Ok(version),
+        None if command == "plasmashell" => {
+            env::var("KDE_SESSION_VERSION").map_err(|_| ReadoutError::MetricNotAvailable)
+        }
+        None => Err(ReadoutError::MetricNotAvailable),
+    }
+}
+
+/// Extracts the version number from the first line of the output of a `--version` flag,
+/// _e.g._ `4.18.3` from `xfce4-session 4.18.3 (Xfce 4.18)`.
+pub(crate) fn parse_version_output(output: &str) -> Option<String> {
+    output
+        .lines()
+        .next()?
+        .split_whitespace()
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))
+        .map(|version| {
+            version
+                .trim_end_matches(|c: char| !c.is_ascii_alphanumeric())
+                .to_string()
+        })
+}
+
 #[cfg(not(any(
     feature = "openwrt",
     target_os = "android",
@@ -84,243 +376,2765 @@ pub(crate) fn session() -> Result<String, ReadoutError> {
     }
 }
 
-#[cfg(target_os = "linux")]
-pub(crate) fn window_manager() -> Result<String, ReadoutError> {
-    use crate::winman::*;
-
-    match session() {
-        Ok(s) => {
-            if s == "Wayland" {
-                let winman_name = match detect_wayland_window_manager() {
-                    Ok(w) => Ok(w),
-                    Err(e) => Err(e),
-                };
-
-                return winman_name;
-            } else if s == "X11" {
-                let winman_name = match detect_xorg_window_manager() {
-                    Ok(w) => Ok(w),
-                    Err(e) => Err(e),
-                };
-
-                return winman_name;
-            }
-
-            Err(ReadoutError::MetricNotAvailable)
+#[cfg(not(any(
+    feature = "openwrt",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+pub(crate) fn session_type() -> Result<String, ReadoutError> {
+    if let Ok(session_type) = env::var("XDG_SESSION_TYPE") {
+        let session_type = session_type.to_lowercase();
+        if !session_type.is_empty() && session_type != "unspecified" {
+            return Ok(session_type);
         }
+    }
 
-        Err(e) => Err(e),
+    if env::var_os("WAYLAND_DISPLAY").is_some() {
+        Ok(String::from("wayland"))
+    } else if env::var_os("DISPLAY").is_some() {
+        Ok(String::from("x11"))
+    } else {
+        Ok(String::from("tty"))
     }
 }
 
-#[cfg(target_family = "unix")]
-fn get_passwd_struct() -> Result<*mut libc::passwd, ReadoutError> {
-    let uid: libc::uid_t = unsafe { libc::geteuid() };
+/// Returns the parent PID of the given process, read from the fourth field of
+/// `/proc/<pid>/stat`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parent_pid(pid: i32) -> Option<i32> {
+    let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
 
-    // Do not call free on passwd pointer according to man page.
-    let passwd = unsafe { libc::getpwuid(uid) };
+    // The command name is enclosed in parentheses and may itself contain spaces or
+    // parentheses, so skip past the last closing one, e.g. "42 (tmux: server) S 1 ...".
+    let (_, fields) = stat.rsplit_once(')')?;
+    fields.split_whitespace().nth(1)?.parse::<i32>().ok()
+}
 
-    if !passwd.is_null() {
-        return Ok(passwd);
-    }
+/// Detects the terminal emulator by walking up the process tree until a known
+/// terminal is found, falling back to `TERM_PROGRAM`, the first ancestor that isn't
+/// a shell, and finally `TERM`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn terminal() -> Result<String, ReadoutError> {
+    // Process names as found in /proc/<pid>/comm, which truncates them to 15 characters.
+    const TERMINALS: [(&str, &str); 24] = [
+        ("alacritty", "Alacritty"),
+        ("kitty", "kitty"),
+        ("foot", "foot"),
+        ("footclient", "foot"),
+        ("wezterm-gui", "WezTerm"),
+        ("konsole", "Konsole"),
+        ("gnome-terminal-", "GNOME Terminal"),
+        ("kgx", "GNOME Console"),
+        ("xfce4-terminal", "Xfce Terminal"),
+        ("mate-terminal", "MATE Terminal"),
+        ("lxterminal", "LXTerminal"),
+        ("qterminal", "QTerminal"),
+        ("terminator", "Terminator"),
+        ("tilix", "Tilix"),
+        ("guake", "Guake"),
+        ("yakuake", "Yakuake"),
+        ("terminology", "Terminology"),
+        ("xterm", "xterm"),
+        ("urxvt", "urxvt"),
+        ("st", "st"),
+        ("sakura", "Sakura"),
+        ("contour", "Contour"),
+        ("blackbox", "Black Box"),
+        ("com.termux", "Termux"),
+    ];
 
-    Err(ReadoutError::Other(String::from(
-        "Unable to read account information.",
-    )))
-}
+    let mut first_non_shell: Option<String> = None;
+    let mut pid = unsafe { libc::getppid() };
 
-#[cfg(target_family = "unix")]
-pub(crate) fn username() -> Result<String, ReadoutError> {
-    let passwd = get_passwd_struct()?;
+    // Stop at init, which also prevents walking in circles.
+    while pid > 1 {
+        let comm = match fs::read_to_string(format!("/proc/{}/comm", pid)) {
+            Ok(comm) => extra::pop_newline(comm),
+            Err(_) => break,
+        };
 
-    let name = unsafe { CStr::from_ptr((*passwd).pw_name) };
-    if let Ok(str) = name.to_str() {
-        return Ok(String::from(str));
-    }
+        if let Some((_, name)) = TERMINALS.iter().find(|(process, _)| *process == comm) {
+            return Ok(name.to_string());
+        }
 
-    Err(ReadoutError::Other(String::from(
-        "Unable to read username for the current UID.",
-    )))
-}
+        if first_non_shell.is_none() && !extra::common_shells().contains(&comm.as_str()) {
+            first_non_shell = Some(comm);
+        }
 
-#[cfg(target_family = "unix")]
-pub(crate) fn shell(shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
-    match kind {
-        ShellKind::Default => {
-            let passwd = get_passwd_struct()?;
-            let shell_name = unsafe { CStr::from_ptr((*passwd).pw_shell) };
+        pid = match parent_pid(pid) {
+            Some(ppid) => ppid,
+            None => break,
+        };
+    }
 
-            if let Ok(str) = shell_name.to_str() {
-                let path = String::from(str);
-
-                match shorthand {
-                    ShellFormat::Relative => {
-                        let path = Path::new(&path);
-                        if let Some(relative) = path.file_name() {
-                            if let Some(shell) = relative.to_str() {
-                                return Ok(shell.to_owned());
-                            }
-                        }
-                    }
-                    _ => {
-                        return Ok(path);
-                    }
-                }
-            }
+    // An empty candidate, e.g. `TERM_PROGRAM=""`, moves on to the next one.
+    let non_empty = |terminal: &String| !terminal.is_empty();
+    env::var("TERM_PROGRAM")
+        .ok()
+        .filter(non_empty)
+        .or_else(|| first_non_shell.filter(non_empty))
+        .or_else(|| env::var("TERM").ok().filter(non_empty))
+        .ok_or_else(|| ReadoutError::Other("Querying terminal information failed".to_string()))
+}
 
-            Err(ReadoutError::Other(String::from(
-                "Unable to read default shell for the current UID.",
-            )))
-        }
-        ShellKind::Current => {
-            let path = PathBuf::from("/proc")
-                .join(unsafe { libc::getppid() }.to_string())
-                .join("comm");
+/// Returns the locale set through the `LC_ALL`, `LC_CTYPE` or `LANG` environment variables,
+/// in that order of precedence.
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn locale() -> Result<String, ReadoutError> {
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| env::var(var).ok())
+        .find(|value| !value.is_empty())
+        .ok_or_else(|| ReadoutError::Other(String::from("No locale is set.")))
+}
 
-            if let Ok(shell) = read_to_string(path) {
-                return Ok(shell);
-            }
+/// Returns the timezone that `/etc/localtime` links to, _e.g._
+/// `/usr/share/zoneinfo/Europe/Berlin`.
+#[cfg(not(any(target_os = "android", target_os = "windows")))]
+pub(crate) fn timezone() -> Result<String, ReadoutError> {
+    let target = fs::read_link("/etc/localtime")
+        .map_err(|_| ReadoutError::Other(String::from("No timezone is set.")))?;
+    let target = target.to_string_lossy();
 
-            Err(ReadoutError::Other(String::from(
-                "Unable to read current shell.",
-            )))
-        }
+    match target.split_once("zoneinfo/") {
+        Some((_, timezone)) if !timezone.is_empty() => Ok(timezone.to_string()),
+        _ => Err(ReadoutError::Other(format!(
+            "Could not determine the timezone from {}",
+            target
+        ))),
     }
 }
 
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
-pub(crate) fn cpu_model_name() -> String {
-    use std::io::{BufRead, BufReader};
-    let file = fs::File::open("/proc/cpuinfo");
-    match file {
-        Ok(content) => {
-            let reader = BufReader::new(content);
-            for line in reader.lines().flatten() {
-                if line.starts_with("model name") {
-                    return line
-                        .replace("model name", "")
-                        .replace(":", "")
-                        .trim()
-                        .to_string();
+/// Looks up a GTK setting, first in `~/.config/gtk-3.0/settings.ini` under `ini_key`,
+/// then through `gsettings` under the `org.gnome.desktop.interface` schema's `gsettings_key`.
+#[cfg(not(any(
+    feature = "openwrt",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+fn gtk_setting(ini_key: &str, gsettings_key: &str) -> Result<String, ReadoutError> {
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")));
+
+    if let Ok(config_dir) = config_dir {
+        if let Ok(settings) = fs::read_to_string(config_dir.join("gtk-3.0/settings.ini")) {
+            let value = settings.lines().find_map(|line| {
+                let (key, value) = line.split_once('=')?;
+                if key.trim() == ini_key {
+                    Some(value.trim().to_string())
+                } else {
+                    None
                 }
+            });
+
+            if let Some(value) = value.filter(|v| !v.is_empty()) {
+                return Ok(value);
             }
-            String::new()
         }
-        Err(_e) => String::new(),
     }
+
+    gnome_interface_setting(gsettings_key).ok_or_else(|| {
+        ReadoutError::Other(format!(
+            "Could not find {} in gtk-3.0/settings.ini or through gsettings.",
+            ini_key
+        ))
+    })
 }
 
-#[cfg(any(target_os = "freebsd", target_os = "macos", target_os = "netbsd"))]
-pub(crate) fn cpu_usage() -> Result<usize, ReadoutError> {
-    let nelem: i32 = 1;
-    let mut value: f64 = 0.0;
-    let value_ptr: *mut f64 = &mut value;
-    let cpu_load = unsafe { libc::getloadavg(value_ptr, nelem) };
-    if cpu_load != -1 {
-        if let Ok(logical_cores) = cpu_cores() {
-            return Ok((value as f64 / logical_cores as f64 * 100.0).round() as usize);
-        }
+/// Returns the value of `key` in the `org.gnome.desktop.interface` schema, as reported
+/// by `gsettings`, without the surrounding quotes.
+#[cfg(not(any(
+    feature = "openwrt",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+fn gnome_interface_setting(key: &str) -> Option<String> {
+    let output = run_with_timeout(
+        Command::new("gsettings").args(["get", "org.gnome.desktop.interface", key]),
+        COMMAND_TIMEOUT,
+    )?;
+
+    let value = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .trim_matches('\'')
+        .to_string();
+
+    if output.status.success() && !value.is_empty() {
+        Some(value)
+    } else {
+        None
     }
-    Err(ReadoutError::Other(format!(
-        "getloadavg failed with return code: {}",
-        cpu_load
-    )))
 }
 
-#[cfg(target_family = "unix")]
-pub(crate) fn cpu_cores() -> Result<usize, ReadoutError> {
-    Ok(num_cpus::get())
+#[cfg(not(any(
+    feature = "openwrt",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+pub(crate) fn theme() -> Result<String, ReadoutError> {
+    gtk_setting("gtk-theme-name", "gtk-theme")
 }
 
-#[cfg(target_family = "unix")]
-pub(crate) fn cpu_physical_cores() -> Result<usize, ReadoutError> {
-    Ok(num_cpus::get_physical())
+#[cfg(not(any(
+    feature = "openwrt",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+pub(crate) fn icons() -> Result<String, ReadoutError> {
+    gtk_setting("gtk-icon-theme-name", "icon-theme")
 }
 
-#[cfg(not(any(target_os = "netbsd", target_os = "windows")))]
-pub(crate) fn disk_space(path: String) -> Result<(AdjustedByte, AdjustedByte), ReadoutError> {
-    let mut s: std::mem::MaybeUninit<libc::statfs> = std::mem::MaybeUninit::uninit();
-    let path = CString::new(path).expect("Could not create C string for disk usage path.");
+/// Returns the accent color picked by the user, read from KDE's `kdeglobals` or,
+/// failing that, from GNOME's `accent-color` setting.
+#[cfg(not(any(
+    feature = "openwrt",
+    target_os = "android",
+    target_os = "macos",
+    target_os = "windows"
+)))]
+pub(crate) fn accent_color() -> Result<(u8, u8, u8), ReadoutError> {
+    let config_dir = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")));
 
-    if unsafe { libc::statfs(path.as_ptr(), s.as_mut_ptr()) } == 0 {
-        let stats: libc::statfs = unsafe { s.assume_init() };
+    if let Ok(config_dir) = config_dir {
+        if let Ok(kdeglobals) = fs::read_to_string(config_dir.join("kdeglobals")) {
+            if let Some(color) = parse_kde_accent_color(&kdeglobals) {
+                return Ok(color);
+            }
+        }
+    }
 
-        let disk_size = stats.f_blocks * stats.f_bsize as u64;
-        let free = stats.f_bavail as u64 * stats.f_bsize as u64;
+    gnome_interface_setting("accent-color")
+        .and_then(|name| gnome_accent_color(&name))
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
 
-        let used_byte =
-            byte_unit::Byte::from_bytes((disk_size - free) as u128).get_appropriate_unit(true);
-        let disk_size_byte =
-            byte_unit::Byte::from_bytes(disk_size as u128).get_adjusted_unit(used_byte.get_unit());
+/// Parses the `AccentColor` entry of the `[General]` group of a `kdeglobals` file,
+/// which KDE stores as `r,g,b`.
+pub(crate) fn parse_kde_accent_color(kdeglobals: &str) -> Option<(u8, u8, u8)> {
+    let mut in_general = false;
 
-        return Ok((used_byte, disk_size_byte));
-    }
+    for line in kdeglobals.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_general = line == "[General]";
+            continue;
+        }
 
-    Err(ReadoutError::Other(String::from(
-        "Error while trying to get statfs structure.",
-    )))
-}
+        if !in_general {
+            continue;
+        }
 
-/// Obtain the value of a specified field from `/proc/meminfo` needed to calculate memory usage
-#[cfg(not(any(target_os = "macos", target_os = "windows")))]
-pub(crate) fn get_meminfo_value(value: &str) -> u64 {
-    use std::io::{BufRead, BufReader};
-    let file = fs::File::open("/proc/meminfo");
-    match file {
-        Ok(content) => {
-            let reader = BufReader::new(content);
-            for line in reader.lines().flatten() {
-                if line.starts_with(value) {
-                    let s_mem_kb: String = line.chars().filter(|c| c.is_digit(10)).collect();
-                    return s_mem_kb.parse::<u64>().unwrap_or(0);
-                }
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "AccentColor" {
+                let mut channels = value.split(',').map(|c| c.trim().parse::<u8>());
+                return match (channels.next(), channels.next(), channels.next()) {
+                    (Some(Ok(r)), Some(Ok(g)), Some(Ok(b))) => Some((r, g, b)),
+                    _ => None,
+                };
             }
-            0
         }
-        Err(_e) => 0,
     }
+
+    None
 }
 
-#[cfg(not(target_os = "windows"))]
-pub(crate) fn local_ip(interface: Option<String>) -> Result<String, ReadoutError> {
-    if let Some(it) = interface {
-        if let Ok(addresses) = if_addrs::get_if_addrs() {
-            for iface in addresses {
-                if iface.name.to_lowercase() == it.to_lowercase() {
-                    return Ok(iface.addr.ip().to_string());
-                }
-            }
+/// Maps the names GNOME uses for its accent colors to their RGB values.
+pub(crate) fn gnome_accent_color(name: &str) -> Option<(u8, u8, u8)> {
+    match name {
+        "blue" => Some((0x35, 0x84, 0xe4)),
+        "teal" => Some((0x21, 0x90, 0xa4)),
+        "green" => Some((0x3a, 0x94, 0x4a)),
+        "yellow" => Some((0xc8, 0x88, 0x00)),
+        "orange" => Some((0xed, 0x5b, 0x00)),
+        "red" => Some((0xe6, 0x2d, 0x42)),
+        "pink" => Some((0xd5, 0x61, 0x99)),
+        "purple" => Some((0x91, 0x41, 0xac)),
+        "slate" => Some((0x6f, 0x83, 0x96)),
+        _ => None,
+    }
+}
+
+/// Extracts the accent color from Android's `theme_customization_overlay_packages`
+/// setting, a JSON object whose palette entries hold `AARRGGBB` hex strings.
+pub(crate) fn parse_android_accent_color(overlays: &str) -> Option<(u8, u8, u8)> {
+    ["accent_color", "system_palette"].iter().find_map(|key| {
+        let field = format!("\"android.theme.customization.{}\"", key);
+        let (_, rest) = overlays.split_once(&field)?;
+        let value = rest.trim_start().strip_prefix(':')?.trim_start();
+        let value = value.strip_prefix('"')?.split('"').next()?;
+        let hex = value.trim_start_matches('#');
+        let rgb = hex.get(hex.len().checked_sub(6)?..)?;
+        if rgb.len() != 6 || !rgb.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
         }
+        let channel = |i: usize| u8::from_str_radix(&rgb[i..i + 2], 16).ok();
 
-        return Err(ReadoutError::Other(String::from(
-            "Unable to get local IP address.",
+        Some((channel(0)?, channel(2)?, channel(4)?))
+    })
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn window_manager() -> Result<String, ReadoutError> {
+    use crate::winman::*;
+
+    match session() {
+        Ok(s) if s == "Wayland" => detect_wayland_window_manager(),
+        Ok(s) if s == "X11" => detect_xorg_window_manager(),
+        Ok(_) => Err(ReadoutError::MetricNotAvailable),
+        // XDG_SESSION_TYPE is not always set, e.g. when starting X through startx.
+        Err(e) => {
+            if env::var_os("WAYLAND_DISPLAY").is_some() {
+                detect_wayland_window_manager()
+            } else if env::var_os("DISPLAY").is_some() {
+                detect_xorg_window_manager()
+            } else {
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Returns the `(idle, total)` jiffies of a `cpu` line of `/proc/stat`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_jiffies(line: &str) -> (u64, u64) {
+    let times: Vec<u64> = line
+        .split_whitespace()
+        .skip(1)
+        .filter_map(|time| time.parse::<u64>().ok())
+        // The guest and guest_nice columns are already accounted for in user and nice.
+        .take(8)
+        .collect();
+
+    // Idle time includes iowait, which is the fifth column.
+    let idle = times.get(3).unwrap_or(&0) + times.get(4).unwrap_or(&0);
+    (idle, times.iter().sum())
+}
+
+/// Returns the `(idle, total)` jiffies of every `cpuN` line of `/proc/stat`, in core order.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn per_core_jiffies() -> Result<Vec<(u64, u64)>, ReadoutError> {
+    let stat = read_file("/proc/stat")?;
+
+    Ok(stat
+        .lines()
+        .filter(|line| match line.strip_prefix("cpu") {
+            Some(rest) => rest.starts_with(|c: char| c.is_ascii_digit()),
+            None => false,
+        })
+        .map(parse_jiffies)
+        .collect())
+}
+
+/// Returns the `(idle, total)` jiffies of all cores combined, _i.e._ the `cpu` line
+/// of `/proc/stat`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn total_jiffies() -> Result<(u64, u64), ReadoutError> {
+    let stat = read_file("/proc/stat")?;
+
+    stat.lines()
+        .find(|line| line.starts_with("cpu "))
+        .map(parse_jiffies)
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+/// Returns the CPU usage in percent between two `(idle, total)` jiffy snapshots.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn jiffies_usage(before: (u64, u64), after: (u64, u64)) -> usize {
+    let total = after.1.saturating_sub(before.1);
+    let idle = after.0.saturating_sub(before.0);
+
+    if total == 0 {
+        return 0;
+    }
+
+    ((total.saturating_sub(idle)) as f64 / total as f64 * 100.0).round() as usize
+}
+
+/// Samples `/proc/stat` twice, `interval` apart, and returns the usage of all cores
+/// combined in percent.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_usage(interval: std::time::Duration) -> Result<usize, ReadoutError> {
+    let before = total_jiffies()?;
+    std::thread::sleep(interval);
+    let after = total_jiffies()?;
+
+    Ok(jiffies_usage(before, after).min(100))
+}
+
+/// Samples `/proc/stat` twice, `interval` apart, and returns the usage of each logical core
+/// in percent.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_usage_per_core(
+    interval: std::time::Duration,
+) -> Result<Vec<usize>, ReadoutError> {
+    let before = per_core_jiffies()?;
+    std::thread::sleep(interval);
+    let after = per_core_jiffies()?;
+
+    if before.is_empty() || before.len() != after.len() {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    Ok(before
+        .iter()
+        .zip(after.iter())
+        .map(|(before, after)| jiffies_usage(*before, *after))
+        .collect())
+}
+
+/// Returns the active mode, _i.e._ the first line of `modes`, of every connected DRM connector
+/// in `/sys/class/drm`, sorted by connector name.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn drm_resolutions() -> Vec<String> {
+    let mut connectors = extra::list_dir_entries(Path::new("/sys/class/drm"));
+    connectors.sort();
+
+    connectors
+        .iter()
+        .filter(
+            |connector| match fs::read_to_string(connector.join("status")) {
+                Ok(status) => status.trim() == "connected",
+                Err(_) => false,
+            },
+        )
+        .filter_map(|connector| {
+            let modes = fs::read_to_string(connector.join("modes")).ok()?;
+            let active = modes.lines().next()?.trim();
+
+            if active.is_empty() {
+                None
+            } else {
+                Some(active.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Returns the amount of data stored in, and the capacity of, all zram devices found in
+/// `block_dir`, _e.g._ `/sys/block`, as a `(used, total)` pair in kilobytes.
+///
+/// The stored amount is the uncompressed size of the data, as reported by the first field
+/// of `mm_stat`, or by `orig_data_size` on kernels older than 4.1.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn zram_usage(block_dir: &Path) -> Result<(u64, u64), ReadoutError> {
+    let devices: Vec<PathBuf> = extra::list_dir_entries(block_dir)
+        .into_iter()
+        .filter(
+            |path| match path.file_name().and_then(|name| name.to_str()) {
+                Some(name) => name.starts_with("zram"),
+                None => false,
+            },
+        )
+        .collect();
+
+    if devices.is_empty() {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    let (mut used, mut total) = (0, 0);
+    for device in devices {
+        let stored = match read_sysfs_string(&device.join("mm_stat")) {
+            Ok(mm_stat) => mm_stat
+                .split_whitespace()
+                .next()
+                .and_then(|orig_data_size| orig_data_size.parse::<u64>().ok())
+                .unwrap_or(0),
+            Err(_) => read_sysfs_u64(&device.join("orig_data_size")).unwrap_or(0),
+        };
+
+        used += stored;
+        total += read_sysfs_u64(&device.join("disksize")).unwrap_or(0);
+    }
+
+    Ok((used / 1024, total / 1024))
+}
+
+/// Returns the entries of `/sys/class/power_supply` whose `type` is `Battery`, sorted by name.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_dirs() -> Vec<PathBuf> {
+    battery_dirs_in(Path::new("/sys/class/power_supply"))
+}
+
+/// Returns the entries of `power_supply` whose `type` is `Battery`, sorted by name, leaving
+/// out AC adapters and USB ports such as `AC`, `ACAD` or `ucsi-source-psy-*`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn battery_dirs_in(power_supply: &Path) -> Vec<PathBuf> {
+    let mut batteries: Vec<PathBuf> = extra::list_dir_entries(power_supply)
+        .into_iter()
+        .filter(|supply| match fs::read_to_string(supply.join("type")) {
+            Ok(supply_type) => supply_type.trim() == "Battery",
+            Err(_) => false,
+        })
+        .collect();
+
+    batteries.sort();
+    batteries
+}
+
+/// Returns the name and percentage of every battery, skipping those whose capacity can't be
+/// read.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_percentages() -> Vec<(String, u8)> {
+    battery_dirs()
+        .iter()
+        .filter_map(|battery| {
+            let name = battery.file_name()?.to_string_lossy().into_owned();
+            let capacity = fs::read_to_string(battery.join("capacity")).ok()?;
+            let percentage = extra::pop_newline(capacity).parse::<u8>().ok()?;
+
+            Some((name, percentage))
+        })
+        .collect()
+}
+
+/// Returns the charging state of the battery found at the given `/sys/class/power_supply` path.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_state(battery: &Path) -> Result<BatteryState, ReadoutError> {
+    let status_path = battery.join("status");
+    let status_text = read_sysfs_string(&status_path)?.to_lowercase();
+
+    match &status_text[..] {
+        "charging" => Ok(BatteryState::Charging),
+        "discharging" => Ok(BatteryState::Discharging),
+        "full" => Ok(BatteryState::Full),
+        "not charging" => Ok(BatteryState::NotCharging),
+        s => Err(ReadoutError::Other(format!(
+            "Got unexpected value '{}' from {}.",
+            s,
+            status_path.to_string_lossy()
+        ))),
+    }
+}
+
+/// Returns the combined charge of the given batteries in percent, _i.e._ the sum of their
+/// current charge over the sum of their full charge, so that a larger battery weighs more.
+///
+/// A single battery reports its own `capacity`. Batteries that don't all expose either
+/// `charge_*` (µAh) or `energy_*` (µWh) values are averaged instead.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn combined_battery_percentage(batteries: &[PathBuf]) -> Result<u8, ReadoutError> {
+    let capacity = |battery: &PathBuf| {
+        read_sysfs_u64(&battery.join("capacity")).map(|percentage| percentage.min(100))
+    };
+
+    if let [battery] = batteries {
+        return Ok(capacity(battery)? as u8);
+    }
+
+    let charges = ["charge", "energy"].iter().find_map(|unit| {
+        batteries
+            .iter()
+            .map(|battery| {
+                let now = read_sysfs_u64(&battery.join(format!("{}_now", unit))).ok()?;
+                let full = read_sysfs_u64(&battery.join(format!("{}_full", unit))).ok()?;
+                Some((now, full))
+            })
+            .collect::<Option<Vec<(u64, u64)>>>()
+    });
+
+    if let Some(charges) = charges {
+        let (now, full) = charges
+            .iter()
+            .fold((0u64, 0u64), |(now, full), (battery_now, battery_full)| {
+                (now + battery_now, full + battery_full)
+            });
+
+        if let Some(percentage) = now.saturating_mul(100).checked_div(full) {
+            return Ok(percentage.min(100) as u8);
+        }
+    }
+
+    let percentages: Vec<u64> = batteries.iter().filter_map(|b| capacity(b).ok()).collect();
+    if percentages.is_empty() {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    Ok((percentages.iter().sum::<u64>() / percentages.len() as u64) as u8)
+}
+
+/// Returns the combined charging state of the given batteries: `Charging` if any of them is
+/// being charged, `Discharging` if any of them is being drained, `Full` if all of them are
+/// full and `NotCharging` otherwise.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn combined_battery_state(batteries: &[PathBuf]) -> Result<BatteryState, ReadoutError> {
+    let mut states = Vec::with_capacity(batteries.len());
+    let mut error = None;
+    for battery in batteries {
+        match battery_state(battery) {
+            Ok(state) => states.push(state),
+            Err(e) => error = error.or(Some(e)),
+        }
+    }
+
+    if states.is_empty() {
+        return Err(error.unwrap_or(ReadoutError::MetricNotAvailable));
+    }
+
+    Ok(
+        if states.iter().any(|s| matches!(s, BatteryState::Charging)) {
+            BatteryState::Charging
+        } else if states
+            .iter()
+            .any(|s| matches!(s, BatteryState::Discharging))
+        {
+            BatteryState::Discharging
+        } else if states.iter().all(|s| matches!(s, BatteryState::Full)) {
+            BatteryState::Full
+        } else {
+            BatteryState::NotCharging
+        },
+    )
+}
+
+/// Returns the health of the battery found at the given `/sys/class/power_supply` path, _i.e._
+/// its full capacity as a percentage of its design capacity. Fuel gauges report either
+/// `charge_full*` (µAh) or `energy_full*` (µWh) pairs.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_health(battery: &Path) -> Result<u8, ReadoutError> {
+    let (full, full_design) = ["charge", "energy"]
+        .iter()
+        .find_map(|unit| {
+            let full_design =
+                read_sysfs_u64(&battery.join(format!("{}_full_design", unit))).ok()?;
+            let full = read_sysfs_u64(&battery.join(format!("{}_full", unit))).ok()?;
+            Some((full, full_design))
+        })
+        .ok_or(ReadoutError::MetricNotAvailable)?;
+
+    if full_design == 0 {
+        return Err(ReadoutError::Other(String::from(
+            "The battery reports a design capacity of zero.",
+        )));
+    }
+
+    Ok((full.saturating_mul(100) / full_design).min(100) as u8)
+}
+
+/// Returns the chemistry of the battery found at the given `/sys/class/power_supply` path,
+/// spelled the way the kernel documents it, _e.g._ `Li-ion`, regardless of the driver's casing.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_technology(battery: &Path) -> Result<String, ReadoutError> {
+    let technology = read_sysfs_string(&battery.join("technology"))
+        .map_err(|_| ReadoutError::MetricNotAvailable)?;
+
+    let normalized = match technology.to_lowercase().replace(['-', ' '], "").as_str() {
+        "" | "unknown" => return Err(ReadoutError::MetricNotAvailable),
+        "liion" | "lion" => "Li-ion",
+        "lipoly" | "lipo" | "lipolymer" => "Li-poly",
+        "life" | "lifepo4" => "LiFe",
+        "limn" => "LiMn",
+        "nimh" => "NiMH",
+        "nicd" => "NiCd",
+        _ => return Ok(technology),
+    };
+
+    Ok(normalized.to_string())
+}
+
+/// Returns the design capacity in µAh of the battery found at the given
+/// `/sys/class/power_supply` path. Fuel gauges that only report energy (µWh)
+/// are converted using the battery's minimum design voltage.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_capacity_design(battery: &Path) -> Result<u64, ReadoutError> {
+    if let Ok(charge) = read_sysfs_u64(&battery.join("charge_full_design")) {
+        return Ok(charge);
+    }
+
+    let energy = read_sysfs_u64(&battery.join("energy_full_design"));
+    let voltage = read_sysfs_u64(&battery.join("voltage_min_design"));
+    match (energy, voltage) {
+        (Ok(energy), Ok(voltage)) if voltage != 0 => {
+            Ok((energy as u128 * 1_000_000 / voltage as u128) as u64)
+        }
+        _ => Err(ReadoutError::MetricNotAvailable),
+    }
+}
+
+/// Estimates the number of minutes until the battery found at the given
+/// `/sys/class/power_supply` path is either empty or fully charged,
+/// depending on the given `state`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_time_remaining(
+    battery: &Path,
+    state: BatteryState,
+) -> Result<u32, ReadoutError> {
+    let read_value = |file: &str| -> Option<f64> {
+        fs::read_to_string(battery.join(file))
+            .ok()
+            .and_then(|value| extra::pop_newline(value).parse::<f64>().ok())
+    };
+
+    // Batteries either report their charge (µAh) and current (µA),
+    // or their energy (µWh) and power (µW).
+    let (now, full, rate) = match (
+        read_value("charge_now"),
+        read_value("charge_full"),
+        read_value("current_now"),
+    ) {
+        (Some(now), Some(full), Some(rate)) => (now, full, rate),
+        _ => match (
+            read_value("energy_now"),
+            read_value("energy_full"),
+            read_value("power_now"),
+        ) {
+            (Some(now), Some(full), Some(rate)) => (now, full, rate),
+            _ => return Err(ReadoutError::MetricNotAvailable),
+        },
+    };
+
+    let remaining = match state {
+        BatteryState::Charging => (full - now).max(0.0),
+        BatteryState::Discharging => now,
+        // The battery is neither being charged nor discharged.
+        BatteryState::Full | BatteryState::NotCharging => {
+            return Err(ReadoutError::MetricNotAvailable)
+        }
+    };
+
+    // Some drivers report a negative current while discharging.
+    let rate = rate.abs();
+    if rate == 0.0 {
+        return Err(ReadoutError::Other(String::from(
+            "The battery reported a current draw of zero, the remaining time cannot be estimated.",
         )));
+    }
+
+    Ok((remaining / rate * 60.0).round() as u32)
+}
+
+/// Returns the power in watts flowing into the battery found at the given
+/// `/sys/class/power_supply` path, which is positive while `state` is charging
+/// and negative while it is discharging.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_power_draw(battery: &Path, state: BatteryState) -> Result<f32, ReadoutError> {
+    let read_value = |file: &str| -> Option<f64> {
+        fs::read_to_string(battery.join(file))
+            .ok()
+            .and_then(|value| extra::pop_newline(value).parse::<f64>().ok())
+    };
+
+    // power_now is in µW, current_now and voltage_now are in µA and µV.
+    let watts = match read_value("power_now") {
+        Some(power) => power / 1e6,
+        None => match (read_value("current_now"), read_value("voltage_now")) {
+            (Some(current), Some(voltage)) => current * voltage / 1e12,
+            _ => return Err(ReadoutError::MetricNotAvailable),
+        },
     };
 
+    // Drivers disagree on the sign of the current, so it is derived from the state instead.
+    Ok(match state {
+        BatteryState::Charging => watts.abs(),
+        BatteryState::Discharging => -watts.abs(),
+        BatteryState::Full | BatteryState::NotCharging => watts,
+    } as f32)
+}
+
+/// Returns the voltage in volts of the battery found at the given
+/// `/sys/class/power_supply` path, which `voltage_now` reports in µV.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_voltage(battery: &Path) -> Result<f32, ReadoutError> {
+    read_sysfs_u64(&battery.join("voltage_now"))
+        .map(|voltage| (voltage as f64 / 1e6) as f32)
+        .map_err(|_| ReadoutError::MetricNotAvailable)
+}
+
+/// Returns the temperature in °C of the battery found at the given
+/// `/sys/class/power_supply` path, which `temp` reports in tenths of a degree.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn battery_temperature(battery: &Path) -> Result<f32, ReadoutError> {
+    read_sysfs_string(&battery.join("temp"))
+        .ok()
+        .and_then(|temp| temp.parse::<i32>().ok())
+        .map(|temp| temp as f32 / 10.0)
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+#[cfg(target_family = "unix")]
+fn get_passwd_struct() -> Result<*mut libc::passwd, ReadoutError> {
+    let uid: libc::uid_t = unsafe { libc::geteuid() };
+
+    // Do not call free on passwd pointer according to man page.
+    let passwd = unsafe { libc::getpwuid(uid) };
+
+    if !passwd.is_null() {
+        return Ok(passwd);
+    }
+
     Err(ReadoutError::Other(String::from(
-            "Please specify a network interface to query (e.g. `interface = \"wlan0\"` in macchina.toml).",
+        "Unable to read account information.",
     )))
 }
 
-pub(crate) fn count_cargo() -> Option<usize> {
-    if let Ok(cargo_home) = std::env::var("CARGO_HOME") {
-        let bin = PathBuf::from(cargo_home).join("bin");
-        if bin.exists() {
-            if let Ok(read_dir) = read_dir(bin) {
-                return Some(read_dir.count());
+#[cfg(target_family = "unix")]
+pub(crate) fn username() -> Result<String, ReadoutError> {
+    let passwd = get_passwd_struct()?;
+
+    let name = unsafe { CStr::from_ptr((*passwd).pw_name) };
+    if let Ok(str) = name.to_str() {
+        return Ok(String::from(str));
+    }
+
+    Err(ReadoutError::Other(String::from(
+        "Unable to read username for the current UID.",
+    )))
+}
+
+/// Formats the path of a shell as requested by `format`, _i.e._ as its file name or as is.
+#[cfg(target_family = "unix")]
+pub(crate) fn format_shell_path(path: &Path, format: &ShellFormat) -> Option<String> {
+    match format {
+        ShellFormat::Relative => path.file_name()?.to_str().map(String::from),
+        ShellFormat::Absolute => path.to_str().map(String::from),
+    }
+}
+
+#[cfg(target_family = "unix")]
+pub(crate) fn shell(shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
+    match kind {
+        ShellKind::Default => {
+            let passwd = get_passwd_struct()?;
+            let shell_name = unsafe { CStr::from_ptr((*passwd).pw_shell) };
+
+            if let Ok(str) = shell_name.to_str() {
+                if let Some(shell) = format_shell_path(Path::new(str), &shorthand) {
+                    return Ok(shell);
+                }
+            }
+
+            Err(ReadoutError::Other(String::from(
+                "Unable to read default shell for the current UID.",
+            )))
+        }
+        ShellKind::Current => {
+            let parent = PathBuf::from("/proc").join(unsafe { libc::getppid() }.to_string());
+
+            // comm only holds the file name, the full path has to be resolved through exe.
+            if let ShellFormat::Absolute = shorthand {
+                if let Ok(exe) = fs::read_link(parent.join("exe")) {
+                    if let Some(shell) = format_shell_path(&exe, &shorthand) {
+                        return Ok(shell);
+                    }
+                }
+            }
+
+            if let Ok(shell) = read_to_string(parent.join("comm")) {
+                return Ok(extra::pop_newline(shell));
             }
+
+            Err(ReadoutError::Other(String::from(
+                "Unable to read current shell.",
+            )))
         }
     }
+}
 
-    if let Ok(home) = std::env::var("HOME") {
-        let bin = PathBuf::from(home).join(".cargo").join("bin");
-        if bin.exists() {
-            if let Ok(read_dir) = read_dir(bin) {
-                return Some(read_dir.count());
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+pub(crate) fn cpu_model_name() -> String {
+    match fs::read_to_string("/proc/cpuinfo") {
+        Ok(cpuinfo) => parse_cpu_model_name(&cpuinfo).unwrap_or_default(),
+        Err(_e) => String::new(),
+    }
+}
+
+/// Returns the first `model name` of the contents of `/proc/cpuinfo`, with runs of
+/// whitespace collapsed, _e.g._ `Intel(R)  Core(TM)` becomes `Intel(R) Core(TM)`.
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn parse_cpu_model_name(cpuinfo: &str) -> Option<String> {
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        if key.trim() == "model name" {
+            Some(value.split_whitespace().collect::<Vec<_>>().join(" "))
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns the feature flags of the processor as listed in `/proc/cpuinfo`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_features() -> Result<Vec<String>, ReadoutError> {
+    parse_cpu_features(&read_file("/proc/cpuinfo")?).ok_or(ReadoutError::MetricNotAvailable)
+}
+
+/// Returns the sorted, deduplicated flags of every `flags` (x86) or `Features` (ARM) line
+/// of the contents of `/proc/cpuinfo`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_cpu_features(cpuinfo: &str) -> Option<Vec<String>> {
+    use std::collections::BTreeSet;
+
+    let mut found = false;
+    let mut features = BTreeSet::new();
+    for line in cpuinfo.lines() {
+        if let Some((key, value)) = line.split_once(':') {
+            if matches!(key.trim(), "flags" | "Features") {
+                found = true;
+                features.extend(value.split_whitespace().map(String::from));
             }
         }
+    }
 
+    if !found {
         return None;
     }
-    None
+
+    Some(features.into_iter().collect())
+}
+
+/// Returns the vendor of the processor as listed in `/proc/cpuinfo`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_vendor() -> Result<String, ReadoutError> {
+    parse_cpu_vendor(&read_file("/proc/cpuinfo")?).ok_or(ReadoutError::MetricNotAvailable)
+}
+
+/// Returns the first `vendor_id` (x86) of the contents of `/proc/cpuinfo`, or the name of
+/// the first `CPU implementer` (ARM), whose hexadecimal codes are assigned by Arm.
+/// Implementers without a known name are returned as their code, _e.g._ `0x99`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_cpu_vendor(cpuinfo: &str) -> Option<String> {
+    let field = |name: &str| {
+        cpuinfo.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            if key.trim() == name && !value.trim().is_empty() {
+                Some(value.trim())
+            } else {
+                None
+            }
+        })
+    };
+
+    if let Some(vendor_id) = field("vendor_id") {
+        return Some(vendor_id.to_string());
+    }
+
+    let implementer = field("CPU implementer")?;
+    let code = u8::from_str_radix(implementer.trim_start_matches("0x"), 16).ok();
+    let vendor = match code {
+        Some(0x41) => "ARM",
+        Some(0x42) => "Broadcom",
+        Some(0x43) => "Cavium",
+        Some(0x46) => "Fujitsu",
+        Some(0x48) => "HiSilicon",
+        Some(0x4e) => "NVIDIA",
+        Some(0x50) => "APM",
+        Some(0x51) => "Qualcomm",
+        Some(0x53) => "Samsung",
+        Some(0x56) => "Marvell",
+        Some(0x61) => "Apple",
+        Some(0x69) => "Intel",
+        Some(0x6d) => "Microsoft",
+        Some(0x70) => "Phytium",
+        Some(0xc0) => "Ampere",
+        _ => implementer,
+    };
+
+    Some(vendor.to_string())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_temperature() -> Result<f32, ReadoutError> {
+    let mut zones = extra::list_dir_entries(Path::new("/sys/class/thermal"));
+    zones.retain(|zone| zone.to_string_lossy().contains("thermal_zone"));
+    zones.sort();
+
+    // Prefer the first thermal zone that is labelled as the CPU (or SoC),
+    // and fall back to thermal_zone0, which usually refers to the CPU too.
+    let cpu_zone = zones.iter().find(|zone| {
+        if let Ok(zone_type) = fs::read_to_string(zone.join("type")) {
+            let zone_type = zone_type.to_lowercase();
+            return zone_type.contains("cpu")
+                || zone_type.contains("soc")
+                || zone_type.contains("x86_pkg_temp");
+        }
+
+        false
+    });
+
+    let zone = match cpu_zone {
+        Some(zone) => zone.to_owned(),
+        None => PathBuf::from("/sys/class/thermal/thermal_zone0"),
+    };
+
+    let temp_text = extra::pop_newline(read_file(zone.join("temp"))?);
+    match temp_text.parse::<f32>() {
+        // The temperature is reported in millidegrees Celsius.
+        Ok(temp) => Ok(temp / 1000.0),
+        Err(e) => Err(ReadoutError::Other(format!(
+            "Could not parse the value '{}' into a digit: {:?}",
+            temp_text, e
+        ))),
+    }
+}
+
+/// Returns the clock speed of cpu0 in MHz, read from cpufreq, or from the `cpu MHz` line of
+/// `/proc/cpuinfo` if cpufreq is unavailable, _e.g._ in virtual machines.
+///
+/// `BogoMIPS` is deliberately not used as a fallback, as it doesn't reflect the clock speed.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_frequency() -> Result<u64, ReadoutError> {
+    let scaling_cur_freq = Path::new("/sys/devices/system/cpu/cpu0/cpufreq/scaling_cur_freq");
+    if let Ok(khz) = fs::read_to_string(scaling_cur_freq) {
+        if let Ok(khz) = extra::pop_newline(khz).parse::<u64>() {
+            return Ok(khz / 1000);
+        }
+    }
+
+    let cpuinfo = read_file("/proc/cpuinfo")?;
+    cpuinfo
+        .lines()
+        .find(|line| line.starts_with("cpu MHz"))
+        .and_then(|line| line.split(':').nth(1))
+        .and_then(|mhz| mhz.trim().parse::<f64>().ok())
+        .map(|mhz| mhz.round() as u64)
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "macos", target_os = "netbsd"))]
+pub(crate) fn cpu_usage() -> Result<usize, ReadoutError> {
+    let nelem: i32 = 1;
+    let mut value: f64 = 0.0;
+    let value_ptr: *mut f64 = &mut value;
+    let cpu_load = unsafe { libc::getloadavg(value_ptr, nelem) };
+    if cpu_load != -1 {
+        if let Ok(logical_cores) = cpu_cores() {
+            return Ok((value as f64 / logical_cores as f64 * 100.0).round() as usize);
+        }
+    }
+    Err(ReadoutError::Other(format!(
+        "getloadavg failed with return code: {}",
+        cpu_load
+    )))
+}
+
+/// Returns the 1, 5 and 15 minute load averages, which make up the first three fields
+/// of `/proc/loadavg`.
+#[cfg(target_os = "linux")]
+pub(crate) fn load_average() -> Result<(f64, f64, f64), ReadoutError> {
+    let loadavg = read_file("/proc/loadavg")?;
+    let loads: Vec<f64> = loadavg
+        .split_whitespace()
+        .take(3)
+        .filter_map(|load| load.parse::<f64>().ok())
+        .collect();
+
+    match loads[..] {
+        [one, five, fifteen] => Ok((one, five, fifteen)),
+        _ => Err(ReadoutError::Other(format!(
+            "Could not parse the load averages from /proc/loadavg: {}",
+            loadavg
+        ))),
+    }
+}
+
+#[cfg(any(target_os = "freebsd", target_os = "macos", target_os = "netbsd"))]
+pub(crate) fn load_average() -> Result<(f64, f64, f64), ReadoutError> {
+    let mut loads: [f64; 3] = [0.0; 3];
+    let ret = unsafe { libc::getloadavg(loads.as_mut_ptr(), 3) };
+    if ret != 3 {
+        return Err(ReadoutError::Other(format!(
+            "getloadavg failed with return code: {}",
+            ret
+        )));
+    }
+
+    Ok((loads[0], loads[1], loads[2]))
+}
+
+/// Returns the number of running processes, _i.e._ the number of entries in `/proc`
+/// whose name is a PID. Entries such as `cpuinfo` or `self` are skipped.
+#[cfg(target_os = "linux")]
+pub(crate) fn process_count() -> Result<usize, ReadoutError> {
+    let entries = read_dir("/proc").map_err(|source| ReadoutError::FileRead {
+        path: PathBuf::from("/proc"),
+        source,
+    })?;
+
+    Ok(entries
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .map(|name| name.bytes().all(|b| b.is_ascii_digit()))
+                .unwrap_or(false)
+        })
+        .count())
+}
+
+/// Returns the sorted names of the kernel modules listed in `/proc/modules`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn loaded_modules() -> Result<Vec<String>, ReadoutError> {
+    Ok(parse_modules(&read_file("/proc/modules")?))
+}
+
+/// Parses the contents of `/proc/modules`, whose first column is the name of the module.
+pub(crate) fn parse_modules(modules: &str) -> Vec<String> {
+    let mut names: Vec<String> = modules
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .map(String::from)
+        .collect();
+
+    names.sort();
+    names
+}
+
+#[cfg(target_family = "unix")]
+pub(crate) fn cpu_cores() -> Result<usize, ReadoutError> {
+    Ok(num_cpus::get())
+}
+
+#[cfg(all(
+    target_family = "unix",
+    not(any(target_os = "linux", target_os = "android"))
+))]
+pub(crate) fn cpu_physical_cores() -> Result<usize, ReadoutError> {
+    Ok(num_cpus::get_physical())
+}
+
+/// Returns the `/sys/devices/system/cpu/cpuN` directory of every logical core.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn cpu_dirs() -> Vec<PathBuf> {
+    extra::list_dir_entries(Path::new("/sys/devices/system/cpu"))
+        .into_iter()
+        .filter(
+            |path| match path.file_name().and_then(|name| name.to_str()) {
+                Some(name) => match name.strip_prefix("cpu") {
+                    Some(id) => !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()),
+                    None => false,
+                },
+                None => false,
+            },
+        )
+        .collect()
+}
+
+/// Groups the logical cores by their maximum frequency, as read from
+/// `cpufreq/cpuinfo_max_freq`, returning `(max_freq_khz, count)` pairs from the
+/// fastest to the slowest cluster.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_cores_by_cluster() -> Result<Vec<(u64, usize)>, ReadoutError> {
+    use std::collections::BTreeMap;
+
+    let mut clusters: BTreeMap<u64, usize> = BTreeMap::new();
+    for cpu in cpu_dirs() {
+        if let Ok(max_freq) = read_sysfs_u64(&cpu.join("cpufreq").join("cpuinfo_max_freq")) {
+            *clusters.entry(max_freq).or_insert(0) += 1;
+        }
+    }
+
+    if clusters.is_empty() {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    Ok(clusters.into_iter().rev().collect())
+}
+
+/// Returns the number of physical cores, counted as the unique core IDs found in the sysfs
+/// CPU topology, falling back to the `physical id` and `core id` pairs of `/proc/cpuinfo`
+/// on kernels whose topology is unreadable.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn cpu_physical_cores() -> Result<usize, ReadoutError> {
+    use std::collections::HashSet;
+
+    let topology_ids: Option<HashSet<_>> = cpu_dirs()
+        .iter()
+        .map(|cpu| {
+            let topology = cpu.join("topology");
+            let package = read_sysfs_u64(&topology.join("physical_package_id")).ok()?;
+            let cluster = read_sysfs_u64(&topology.join("cluster_id")).ok();
+            let core = read_sysfs_u64(&topology.join("core_id")).ok()?;
+            Some((package, cluster, core))
+        })
+        .collect();
+
+    match topology_ids {
+        Some(ids) if !ids.is_empty() => Ok(ids.len()),
+        _ => read_file("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| physical_cores_from_cpuinfo(&cpuinfo))
+            .ok_or(ReadoutError::MetricNotAvailable),
+    }
+}
+
+/// Counts the unique `physical id` and `core id` pairs of the processors listed in the
+/// contents of `/proc/cpuinfo`. Processors that don't report them, as is common on ARM,
+/// are counted as a core of their own.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn physical_cores_from_cpuinfo(cpuinfo: &str) -> Option<usize> {
+    use std::collections::HashSet;
+
+    let mut cores = HashSet::new();
+
+    for block in cpuinfo.split("\n\n") {
+        let mut processor = None;
+        let mut physical_id = None;
+        let mut core_id = None;
+
+        for line in block.lines() {
+            if let Some((key, value)) = line.split_once(':') {
+                let value = value.trim().parse::<usize>().ok();
+                match key.trim() {
+                    "processor" => processor = value,
+                    "physical id" => physical_id = value,
+                    "core id" => core_id = value,
+                    _ => {}
+                }
+            }
+        }
+
+        match (processor, physical_id, core_id) {
+            (_, Some(physical_id), Some(core_id)) => cores.insert((physical_id, core_id)),
+            (Some(processor), _, _) => cores.insert((usize::MAX, processor)),
+            _ => false,
+        };
+    }
+
+    if cores.is_empty() {
+        None
+    } else {
+        Some(cores.len())
+    }
+}
+
+#[cfg(not(any(target_os = "netbsd", target_os = "windows")))]
+pub(crate) fn disk_space(path: String) -> Result<(AdjustedByte, AdjustedByte), ReadoutError> {
+    let mut s: std::mem::MaybeUninit<libc::statfs> = std::mem::MaybeUninit::uninit();
+    let path = CString::new(path).expect("Could not create C string for disk usage path.");
+
+    if unsafe { libc::statfs(path.as_ptr(), s.as_mut_ptr()) } == 0 {
+        let stats: libc::statfs = unsafe { s.assume_init() };
+
+        let disk_size = stats.f_blocks * stats.f_bsize as u64;
+        let free = stats.f_bavail as u64 * stats.f_bsize as u64;
+
+        let used_byte =
+            byte_unit::Byte::from_bytes((disk_size - free) as u128).get_appropriate_unit(true);
+        let disk_size_byte =
+            byte_unit::Byte::from_bytes(disk_size as u128).get_adjusted_unit(used_byte.get_unit());
+
+        return Ok((used_byte, disk_size_byte));
+    }
+
+    Err(ReadoutError::Other(String::from(
+        "Error while trying to get statfs structure.",
+    )))
+}
+
+/// Returns the `statvfs` structure of the filesystem mounted at the given path.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn statvfs(path: &Path) -> Result<libc::statvfs, ReadoutError> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let mut s: std::mem::MaybeUninit<libc::statvfs> = std::mem::MaybeUninit::uninit();
+    let c_path = match CString::new(path.as_os_str().as_bytes()) {
+        Ok(c_path) => c_path,
+        Err(_) => {
+            return Err(ReadoutError::Other(format!(
+                "Could not create C string for disk usage path: {:?}",
+                path
+            )))
+        }
+    };
+
+    if unsafe { libc::statvfs(c_path.as_ptr(), s.as_mut_ptr()) } == 0 {
+        return Ok(unsafe { s.assume_init() });
+    }
+
+    Err(ReadoutError::Other(format!(
+        "Error while trying to get statvfs structure of {:?}.",
+        path
+    )))
+}
+
+/// Computes the used memory from the values reported by the kernel.
+///
+/// The values are read one after another, so the cached, reclaimable and buffered memory can
+/// momentarily exceed the memory that isn't free, in which case `0` is returned.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn used_memory(
+    total: u64,
+    free: u64,
+    cached: u64,
+    reclaimable: u64,
+    buffers: u64,
+) -> u64 {
+    total
+        .saturating_sub(free)
+        .saturating_sub(cached)
+        .saturating_sub(reclaimable)
+        .saturating_sub(buffers)
+}
+
+/// Obtain the value of a specified field from `/proc/meminfo` needed to calculate memory usage
+///
+/// Returns `ReadoutError::MetricNotAvailable` if the kernel does not report the field.
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+pub(crate) fn get_meminfo_value(value: &str) -> Result<u64, ReadoutError> {
+    let meminfo = read_file("/proc/meminfo")?;
+    parse_meminfo_value(&meminfo, value)
+}
+
+/// Parses the value of `key` out of the contents of `/proc/meminfo`, whose lines look
+/// like `MemTotal:       16318600 kB`.
+///
+/// The key has to match exactly, so that _e.g._ `Cached` doesn't pick up `SwapCached`.
+/// A missing key or a value that isn't a number yields `ReadoutError::MetricNotAvailable`.
+pub(crate) fn parse_meminfo_value(meminfo: &str, key: &str) -> Result<u64, ReadoutError> {
+    meminfo
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim() == key)
+        .and_then(|(_, value)| value.split_whitespace().next()?.parse::<u64>().ok())
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn local_ip(interface: Option<String>) -> Result<String, ReadoutError> {
+    // Without an explicit interface, prefer the one carrying the default route over
+    // whichever virtual or VPN interface happens to be listed first.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if interface.is_none() {
+        if let Some(ip) = default_interface()
+            .and_then(|it| local_ips(Some(it)).ok())
+            .and_then(|ips| ips.into_iter().find(|ip| ip.is_ipv4()))
+        {
+            return Ok(ip.to_string());
+        }
+    }
+
+    match local_ips(interface)?.into_iter().find(|ip| ip.is_ipv4()) {
+        Some(ip) => Ok(ip.to_string()),
+        None => Err(ReadoutError::Other(String::from(
+            "Unable to get local IP address.",
+        ))),
+    }
+}
+
+/// Returns every non-loopback IPv4 and IPv6 address of the given interface,
+/// or of all interfaces if none is given.
+#[cfg(not(target_os = "windows"))]
+pub(crate) fn local_ips(interface: Option<String>) -> Result<Vec<std::net::IpAddr>, ReadoutError> {
+    let addresses = if_addrs::get_if_addrs()
+        .map_err(|e| ReadoutError::Other(format!("Unable to list network interfaces: {}", e)))?;
+
+    let ips: Vec<std::net::IpAddr> = addresses
+        .into_iter()
+        .filter(|iface| !iface.is_loopback())
+        .filter(|iface| match &interface {
+            Some(it) => iface.name.to_lowercase() == it.to_lowercase(),
+            None => true,
+        })
+        .map(|iface| iface.ip())
+        .collect();
+
+    if ips.is_empty() {
+        return Err(ReadoutError::Other(String::from(
+            "Unable to get local IP address.",
+        )));
+    }
+
+    Ok(ips)
+}
+
+/// How long `public_ip` waits for the echo service to answer.
+#[cfg(feature = "network")]
+const PUBLIC_IP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Asks the HTTP echo service at `endpoint` for the public IP address of the host.
+#[cfg(feature = "network")]
+pub(crate) fn public_ip(endpoint: &str) -> Result<std::net::IpAddr, ReadoutError> {
+    let agent = ureq::AgentBuilder::new().timeout(PUBLIC_IP_TIMEOUT).build();
+
+    let body = match agent.get(endpoint).call() {
+        Ok(response) => response.into_string()?,
+        Err(ureq::Error::Transport(transport)) => {
+            let timed_out = std::error::Error::source(&transport)
+                .and_then(|source| source.downcast_ref::<std::io::Error>())
+                .map(|e| {
+                    matches!(
+                        e.kind(),
+                        std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock
+                    )
+                })
+                .unwrap_or(false);
+
+            if timed_out {
+                return Err(ReadoutError::Timeout);
+            }
+
+            return Err(ReadoutError::Other(format!(
+                "Could not query the public IP address: {}",
+                transport
+            )));
+        }
+        Err(e) => {
+            return Err(ReadoutError::Other(format!(
+                "Could not query the public IP address: {}",
+                e
+            )))
+        }
+    };
+
+    body.trim().parse().map_err(|_| {
+        ReadoutError::Other(format!(
+            "{} did not answer with an IP address: {}",
+            endpoint,
+            body.trim()
+        ))
+    })
+}
+
+/// Returns the name of the network interface that carries the default route,
+/// as listed in `/proc/net/route`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn default_interface() -> Option<String> {
+    parse_default_interface(&fs::read_to_string("/proc/net/route").ok()?)
+}
+
+/// Picks the interface of the default route out of a `/proc/net/route` table.
+///
+/// Only routes that are up and cover 0.0.0.0/0 count, which rules out the 0.0.0.0/1 halves
+/// VPNs install to override the default route; among those, the lowest metric wins.
+pub(crate) fn parse_default_interface(route_table: &str) -> Option<String> {
+    const RTF_UP: u32 = 0x1;
+
+    // Skip the header, whose columns are Iface, Destination, Gateway, Flags, RefCnt,
+    // Use, Metric, Mask, and so on.
+    route_table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (interface, destination, flags, metric, mask) = match fields[..] {
+                [interface, destination, _, flags, _, _, metric, mask, ..] => {
+                    (interface, destination, flags, metric, mask)
+                }
+                _ => return None,
+            };
+
+            let flags = u32::from_str_radix(flags, 16).ok()?;
+            if destination != "00000000" || mask != "00000000" || flags & RTF_UP == 0 {
+                return None;
+            }
+
+            Some((metric.parse::<u32>().ok()?, interface))
+        })
+        .min_by_key(|(metric, _)| *metric)
+        .map(|(_, interface)| interface.to_string())
+}
+
+/// Reads the counter called `statistic` (e.g. `tx_bytes`) of the given network interface
+/// from `/sys/class/net/<interface>/statistics`, defaulting to the interface carrying the
+/// default route.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn network_statistic(
+    interface: Option<String>,
+    statistic: &str,
+) -> Result<u64, ReadoutError> {
+    let interface = match interface {
+        Some(it) => it,
+        None => default_interface().ok_or(ReadoutError::MetricNotAvailable)?,
+    };
+
+    let statistics = Path::new("/sys/class/net")
+        .join(&interface)
+        .join("statistics");
+    if !statistics.is_dir() {
+        return Err(ReadoutError::MetricNotAvailable);
+    }
+
+    read_sysfs_u64(&statistics.join(statistic))
+}
+
+/// Returns the negotiated link speed in Mbps of the given network interface, defaulting to
+/// the interface carrying the default route. Interfaces without a carrier, and most virtual
+/// ones, report a speed of `-1` or none at all.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn link_speed(interface: Option<String>) -> Result<u64, ReadoutError> {
+    let interface = match interface {
+        Some(it) => it,
+        None => default_interface().ok_or(ReadoutError::MetricNotAvailable)?,
+    };
+
+    // Reading the speed of an interface that is down fails with EINVAL.
+    let speed = read_sysfs_string(&Path::new("/sys/class/net").join(&interface).join("speed"))
+        .map_err(|_| ReadoutError::MetricNotAvailable)?;
+
+    match speed.parse::<i64>() {
+        Ok(speed) if speed > 0 => Ok(speed as u64),
+        _ => Err(ReadoutError::MetricNotAvailable),
+    }
+}
+
+/// Returns the hardware address of the given network interface in its canonical
+/// lowercase, colon-separated form. If no interface is given, the one carrying the
+/// default route is used, falling back to the first interface with a usable address.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn mac_address(interface: Option<String>) -> Result<String, ReadoutError> {
+    let net = Path::new("/sys/class/net");
+    let hardware_address = |name: &str| -> Option<String> {
+        let iface = net.join(name);
+        // ARPHRD_LOOPBACK
+        if read_sysfs_u64(&iface.join("type")).ok()? == 772 {
+            return None;
+        }
+
+        read_sysfs_string(&iface.join("address"))
+            .ok()
+            .and_then(|address| normalize_mac_address(&address))
+    };
+
+    if let Some(interface) = interface {
+        return hardware_address(&interface).ok_or_else(|| {
+            ReadoutError::Other(format!(
+                "Interface {} does not have a hardware address.",
+                interface
+            ))
+        });
+    }
+
+    if let Some(address) = default_interface().and_then(|it| hardware_address(&it)) {
+        return Ok(address);
+    }
+
+    let mut interfaces: Vec<String> = read_dir(net)
+        .map_err(|_| ReadoutError::MetricNotAvailable)?
+        .map_while(Result::ok)
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    interfaces.sort();
+
+    interfaces
+        .iter()
+        .find_map(|it| hardware_address(it))
+        .ok_or(ReadoutError::MetricNotAvailable)
+}
+
+/// Converts a hardware address to lowercase, colon-separated form, returning `None`
+/// if it is malformed or all zeros.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn normalize_mac_address(address: &str) -> Option<String> {
+    let octets: Vec<u8> = address
+        .trim()
+        .split([':', '-'])
+        .map(|octet| u8::from_str_radix(octet, 16).ok())
+        .collect::<Option<_>>()?;
+
+    if octets.len() != 6 || octets.iter().all(|&octet| octet == 0) {
+        return None;
+    }
+
+    Some(
+        octets
+            .iter()
+            .map(|octet| format!("{:02x}", octet))
+            .collect::<Vec<_>>()
+            .join(":"),
+    )
+}
+
+pub(crate) fn count_cargo() -> Option<usize> {
+    if let Ok(cargo_home) = std::env::var("CARGO_HOME") {
+        let bin = PathBuf::from(cargo_home).join("bin");
+        if bin.exists() {
+            if let Ok(read_dir) = read_dir(bin) {
+                return Some(read_dir.count());
+            }
+        }
+    }
+
+    if let Ok(home) = std::env::var("HOME") {
+        let bin = PathBuf::from(home).join(".cargo").join("bin");
+        if bin.exists() {
+            if let Ok(read_dir) = read_dir(bin) {
+                return Some(read_dir.count());
+            }
+        }
+
+        return None;
+    }
+    None
+}
+
+/// Returns the number of installed Python distributions, found by
+/// enumerating the `*.dist-info` and `*.egg-info` entries of the active
+/// site-packages directories.
+///
+/// If `VIRTUAL_ENV` is set, only the virtual environment is inspected,
+/// otherwise `python3` is asked for its global and user site-packages.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn count_pip() -> Option<usize> {
+    use std::collections::HashSet;
+
+    let site_packages: Vec<PathBuf> = match env::var("VIRTUAL_ENV") {
+        Ok(venv) => extra::list_dir_entries(&PathBuf::from(venv).join("lib"))
+            .into_iter()
+            .map(|python| python.join("site-packages"))
+            .collect(),
+        Err(_) => {
+            let output = run_with_timeout(
+                Command::new("python3").args([
+                    "-c",
+                    "import site; print('\\n'.join(site.getsitepackages() + [site.getusersitepackages()]))",
+                ]),
+                COMMAND_TIMEOUT,
+            )?;
+
+            String::from_utf8(output.stdout)
+                .ok()?
+                .lines()
+                .map(PathBuf::from)
+                .collect()
+        }
+    };
+
+    let distributions: HashSet<String> = site_packages
+        .iter()
+        .flat_map(|dir| extra::list_dir_entries(dir))
+        .filter_map(|entry| {
+            let file_name = entry.file_name()?.to_str()?;
+            let stem = file_name
+                .strip_suffix(".dist-info")
+                .or_else(|| file_name.strip_suffix(".egg-info"))?;
+
+            // e.g. "requests-2.31.0" or "Flask_Cors-4.0.0-py3.11"
+            let name = stem.split('-').next()?;
+            Some(name.to_lowercase().replace(&['_', '.'][..], "-"))
+        })
+        .collect();
+
+    if !distributions.is_empty() {
+        return Some(distributions.len());
+    }
+
+    None
+}
+
+/// Returns the number of packages installed into the user's Nix profile and, on NixOS, the
+/// system profile.
+///
+/// Profiles are counted through their manifest if they have one, falling back to the direct
+/// references of the profile's store path, which is a single, cheap query of the Nix database,
+/// unlike walking all of its requisites.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn count_nix() -> Option<usize> {
+    let mut profiles = vec![PathBuf::from("/run/current-system/sw")];
+    if let Ok(home) = env::var("HOME") {
+        profiles.push(PathBuf::from(home).join(".nix-profile"));
+    }
+    if let Ok(user) = env::var("USER") {
+        profiles.push(PathBuf::from("/etc/profiles/per-user").join(user));
+    }
+
+    let count_profile = |profile: &PathBuf| -> Option<usize> {
+        // Profiles managed by `nix profile` list one "storePaths" array per package.
+        if let Ok(manifest) = fs::read_to_string(profile.join("manifest.json")) {
+            return Some(manifest.matches("\"storePaths\"").count());
+        }
+
+        // Profiles managed by `nix-env` describe every package as a derivation.
+        if let Ok(manifest) = fs::read_to_string(profile.join("manifest.nix")) {
+            return Some(manifest.matches("type = \"derivation\"").count());
+        }
+
+        if !extra::which("nix-store") {
+            return None;
+        }
+
+        let output = run_with_timeout(
+            Command::new("nix-store")
+                .args(["--query", "--references"])
+                .arg(profile),
+            COMMAND_TIMEOUT,
+        )?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        extra::count_lines(String::from_utf8(output.stdout).ok()?)
+    };
+
+    let count: usize = profiles
+        .iter()
+        .filter(|profile| profile.exists())
+        .filter_map(count_profile)
+        .sum();
+
+    if count != 0 {
+        return Some(count);
+    }
+
+    None
+}
+
+/// How long a readout may wait for an external command it runs, _e.g._ a package
+/// manager or `nvidia-smi`, before giving up.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "windows",
+    target_os = "freebsd",
+    target_os = "netbsd"
+))]
+pub(crate) const COMMAND_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Runs `command` to completion and returns its output, or `None` if it couldn't be
+/// started or didn't exit within `timeout`, in which case it is killed.
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "windows",
+    target_os = "freebsd",
+    target_os = "netbsd"
+))]
+pub(crate) fn run_with_timeout(
+    command: &mut Command,
+    timeout: std::time::Duration,
+) -> Option<std::process::Output> {
+    use std::io::Read;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .ok()?;
+
+    // The pipes are drained while waiting, so that a chatty command can't
+    // block on a full pipe and run into the timeout.
+    fn drain<R: Read + Send + 'static>(mut pipe: R) -> thread::JoinHandle<Vec<u8>> {
+        thread::spawn(move || {
+            let mut buffer = Vec::new();
+            let _ = pipe.read_to_end(&mut buffer);
+            buffer
+        })
+    }
+
+    let stdout = drain(child.stdout.take()?);
+    let stderr = drain(child.stderr.take()?);
+
+    let start = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if start.elapsed() < timeout => thread::sleep(Duration::from_millis(10)),
+            _ => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            }
+        }
+    };
+
+    Some(std::process::Output {
+        status,
+        stdout: stdout.join().ok()?,
+        stderr: stderr.join().ok()?,
+    })
+}
+
+/// A package manager along with the function that counts its installed packages.
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "windows"))]
+pub(crate) type PackageCounter = (PackageManager, fn() -> Option<usize>);
+
+/// Runs every counter on its own thread, so that the total time is bounded by the
+/// slowest counter rather than their sum, and returns the counts sorted by package manager.
+///
+/// Package managers with nothing installed are left out, _e.g._ when `rpm -qa` succeeds
+/// on a host whose packages are managed by something else.
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "windows"))]
+pub(crate) fn count_packages(counters: Vec<PackageCounter>) -> Vec<(PackageManager, usize)> {
+    let handles: Vec<_> = counters
+        .into_iter()
+        .map(|(manager, counter)| (manager, std::thread::spawn(counter)))
+        .collect();
+
+    let mut packages: Vec<(PackageManager, usize)> = handles
+        .into_iter()
+        .filter_map(|(manager, handle)| Some((manager, handle.join().ok()??)))
+        .filter(|(_, count)| *count != 0)
+        .collect();
+
+    packages.sort_by(|a, b| a.0.cmp(&b.0));
+    packages
+}
+
+/// Returns the number of installed flatpak applications, counting those
+/// installed both system-wide and per-user only once.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn count_flatpak() -> Option<usize> {
+    use std::collections::HashSet;
+
+    let mut flatpak_dirs = vec![PathBuf::from("/var/lib/flatpak/app")];
+    if let Ok(home) = env::var("HOME") {
+        flatpak_dirs.push(PathBuf::from(home).join(".local/share/flatpak/app"));
+    }
+
+    let apps: HashSet<_> = flatpak_dirs
+        .iter()
+        .flat_map(|dir| extra::list_dir_entries(dir))
+        .filter_map(|app| app.file_name().map(|name| name.to_owned()))
+        .collect();
+
+    if !apps.is_empty() {
+        return Some(apps.len());
+    }
+
+    None
+}
+
+/// Returns the existing directories searched for AppImages, which have no registry of
+/// their own: `~/Applications`, `~/.local/bin`, the XDG applications directory
+/// (`$XDG_DATA_HOME/applications`, by default `~/.local/share/applications`) and every
+/// directory of `$PATH`.
+#[cfg(target_os = "linux")]
+pub(crate) fn appimage_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(home) = env::var_os("HOME").map(PathBuf::from) {
+        dirs.push(home.join("Applications"));
+        dirs.push(home.join(".local/bin"));
+
+        let data_home = env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(".local/share"));
+        dirs.push(data_home.join("applications"));
+    }
+
+    if let Some(path) = env::var_os("PATH") {
+        dirs.extend(env::split_paths(&path));
+    }
+
+    dirs.retain(|dir| dir.is_dir());
+    dirs
+}
+
+/// Returns the number of `*.AppImage` files in the given directories. Files reachable
+/// through several directories or symlinks are counted once.
+#[cfg(target_os = "linux")]
+pub(crate) fn count_appimages(dirs: &[PathBuf]) -> Option<usize> {
+    use std::collections::HashSet;
+
+    let appimages: HashSet<PathBuf> = dirs
+        .iter()
+        .flat_map(|dir| extra::list_dir_entries(dir))
+        .filter(|path| {
+            matches!(path.extension(), Some(extension) if extension.eq_ignore_ascii_case("AppImage"))
+        })
+        .filter_map(|path| fs::canonicalize(path).ok())
+        .filter(|path| path.is_file())
+        .collect();
+
+    if !appimages.is_empty() {
+        return Some(appimages.len());
+    }
+
+    None
+}
+
+/// Returns every prefix Homebrew is installed to that holds a `Cellar`: `HOMEBREW_PREFIX`
+/// and each of the default prefixes, as a Mac can have both an Apple Silicon and an Intel
+/// installation side by side.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub(crate) fn homebrew_prefixes() -> Vec<PathBuf> {
+    let mut prefixes = Vec::new();
+    if let Some(prefix) = env::var_os("HOMEBREW_PREFIX") {
+        prefixes.push(PathBuf::from(prefix));
+    }
+
+    // Apple Silicon, Intel Macs and Linux, in that order.
+    prefixes.push(PathBuf::from("/opt/homebrew"));
+    prefixes.push(PathBuf::from("/usr/local"));
+    prefixes.push(PathBuf::from("/home/linuxbrew/.linuxbrew"));
+    if let Ok(home) = env::var("HOME") {
+        prefixes.push(PathBuf::from(home).join(".linuxbrew"));
+    }
+
+    let mut unique = Vec::new();
+    for prefix in prefixes {
+        if prefix.join("Cellar").is_dir() && !unique.contains(&prefix) {
+            unique.push(prefix);
+        }
+    }
+    unique
+}
+
+/// Returns the number of formulae and casks installed with Homebrew to the given
+/// prefixes, _i.e._ the directories in their `Cellar` and `Caskroom`. This is much
+/// faster than `brew list`.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+pub(crate) fn count_homebrew(prefixes: &[PathBuf]) -> Option<usize> {
+    let packages = prefixes
+        .iter()
+        .flat_map(|prefix| {
+            ["Cellar", "Caskroom"]
+                .iter()
+                .map(move |dir| prefix.join(dir))
+        })
+        .flat_map(|dir| extra::list_dir_entries(&dir))
+        .filter(|package| package.is_dir())
+        .count();
+
+    if packages != 0 {
+        return Some(packages);
+    }
+
+    None
+}
+
+/// Returns the number of packages in the given pacman database, _e.g._
+/// `/var/lib/pacman/local`, where every installed package is a directory
+/// next to the `ALPM_DB_VERSION` file.
+#[cfg(target_os = "linux")]
+pub(crate) fn count_pacman(local_dir: &Path) -> Option<usize> {
+    let packages = extra::list_dir_entries(local_dir)
+        .iter()
+        .filter(|path| path.is_dir())
+        .count();
+
+    if packages != 0 {
+        return Some(packages);
+    }
+
+    None
+}
+
+/// Returns the number of packages in the given portage database, _e.g._ `/var/db/pkg`,
+/// where every installed package is a `<category>/<package>-<version>` directory.
+/// The `-MERGING-` directories of packages that are still being installed are skipped.
+#[cfg(target_os = "linux")]
+pub(crate) fn count_portage(db: &Path) -> Option<usize> {
+    let is_package_dir = |path: &PathBuf| {
+        path.is_dir()
+            && match path.file_name().and_then(|name| name.to_str()) {
+                Some(name) => !name.starts_with('-') && !name.starts_with('.'),
+                None => false,
+            }
+    };
+
+    let packages: usize = extra::list_dir_entries(db)
+        .iter()
+        .filter(|category| is_package_dir(category))
+        .map(|category| {
+            extra::list_dir_entries(category)
+                .iter()
+                .filter(|package| is_package_dir(package))
+                .count()
+        })
+        .sum();
+
+    if packages != 0 {
+        return Some(packages);
+    }
+
+    None
+}
+
+/// Returns the number of installed packages in the given xbps package database, _e.g._
+/// `/var/db/xbps/pkgdb-0.38.plist`, which is a property list holding a dictionary for every
+/// package. Only packages whose `state` is `installed` are counted, leaving out those that
+/// are merely unpacked or half-removed.
+#[cfg(target_os = "linux")]
+pub(crate) fn count_xbps(pkgdb: &Path) -> Option<usize> {
+    let plist = fs::read_to_string(pkgdb).ok()?;
+
+    let mut lines = plist.lines().map(str::trim).filter(|line| !line.is_empty());
+    let mut packages = 0;
+    while let Some(line) = lines.next() {
+        if line == "<key>state</key>" && lines.next() == Some("<string>installed</string>") {
+            packages += 1;
+        }
+    }
+
+    if packages != 0 {
+        return Some(packages);
+    }
+
+    None
+}
+
+/// Returns the number of installed snaps, found either as directories under
+/// `snap_root` (_e.g._ `/snap`) or as `<name>_<revision>.snap` images in `snaps_dir`
+/// (_e.g._ `/var/lib/snapd/snaps`). Every revision of a snap is counted once.
+///
+/// With `apps_only`, base snaps such as `core22`, `snapd` and `bare` are left out.
+#[cfg(target_os = "linux")]
+pub(crate) fn count_snaps(snap_root: &Path, snaps_dir: &Path, apps_only: bool) -> Option<usize> {
+    use std::collections::HashSet;
+
+    fn is_base_snap(name: &str) -> bool {
+        match name {
+            "snapd" | "bare" => true,
+            _ => match name.strip_prefix("core") {
+                Some(version) => version.bytes().all(|b| b.is_ascii_digit()),
+                None => false,
+            },
+        }
+    }
+
+    let mounted = extra::list_dir_entries(snap_root)
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .filter_map(|path| path.file_name()?.to_str().map(String::from))
+        .filter(|name| name != "bin" && name != "README");
+
+    let images = extra::list_dir_entries(snaps_dir)
+        .into_iter()
+        .filter(|path| extra::path_extension(path) == Some("snap"))
+        .filter_map(|path| {
+            let stem = path.file_stem()?.to_str()?;
+            stem.split_once('_').map(|(name, _)| name.to_string())
+        });
+
+    let snaps: HashSet<String> = mounted
+        .chain(images)
+        .filter(|name| !(apps_only && is_base_snap(name)))
+        .collect();
+
+    if !snaps.is_empty() {
+        return Some(snaps.len());
+    }
+
+    None
+}
+
+/// Builds the model name of the machine from the DMI attributes found in `dmi_dir`,
+/// _e.g._ `/sys/class/dmi/id`, combining `sys_vendor` with `product_name` and
+/// `product_version`. If the firmware leaves the product fields blank or generic,
+/// as is common on custom builds, `board_name` is used instead.
+#[cfg(target_os = "linux")]
+pub(crate) fn host_model(dmi_dir: &Path) -> Result<String, ReadoutError> {
+    const PLACEHOLDERS: [&str; 8] = [
+        "to be filled by o.e.m.",
+        "system product name",
+        "system version",
+        "system manufacturer",
+        "default string",
+        "not applicable",
+        "not specified",
+        "none",
+    ];
+
+    let read = |attribute: &str| -> String {
+        read_sysfs_string(&dmi_dir.join(attribute))
+            .map(|value| value.trim().to_string())
+            .ok()
+            .filter(|value| !PLACEHOLDERS.contains(&value.to_lowercase().as_str()))
+            .unwrap_or_default()
+    };
+
+    let vendor = read("sys_vendor");
+    let name = read("product_name");
+    let version = read("product_version");
+
+    let (family, product) = if name.is_empty() {
+        (read("board_name"), String::new())
+    } else if vendor.eq_ignore_ascii_case("lenovo") && !version.is_empty() {
+        // Lenovo stores the machine type in product_name and the model in product_version.
+        (version, name)
+    } else {
+        (format!("{} {}", name, version), String::new())
+    };
+
+    if family.trim().is_empty() {
+        return Err(ReadoutError::Other(String::from(
+            "Your manufacturer may have not specified your machine's product information.",
+        )));
+    }
+
+    Ok(extra::normalize_machine_name(&vendor, &family, &product))
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn test_hostname() {
+        // The second field of /proc/self/statm is the resident set size in pages.
+        let resident_bytes = || -> u64 {
+            let statm = read_sysfs_string(Path::new("/proc/self/statm")).unwrap();
+            let pages: u64 = statm.split_whitespace().nth(1).unwrap().parse().unwrap();
+            pages * unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as u64
+        };
+
+        let expected = read_sysfs_string(Path::new("/proc/sys/kernel/hostname")).unwrap();
+        assert_eq!(hostname().unwrap(), expected);
+
+        // Leaking even the smallest buffer per call would add up to well over the bound,
+        // which leaves room for the allocations of the tests running alongside.
+        let before = resident_bytes();
+        for _ in 0..200_000 {
+            assert!(!hostname().unwrap().is_empty());
+        }
+        let growth = resident_bytes().saturating_sub(before);
+
+        assert!(growth < 8 * 1024 * 1024, "RSS grew by {} bytes", growth);
+    }
+
+    #[test]
+    fn test_utsname_field() {
+        let field = |bytes: &[u8]| -> Vec<libc::c_char> {
+            bytes.iter().map(|&b| b as libc::c_char).collect()
+        };
+
+        assert_eq!(
+            utsname_field(&field(b"6.1.25-android14\0garbage")).ok(),
+            Some(String::from("6.1.25-android14"))
+        );
+        assert_eq!(
+            utsname_field(&field(b"Linux")).ok(),
+            Some(String::from("Linux"))
+        );
+        assert!(matches!(
+            utsname_field(&field(b"Lin\xffux\0")),
+            Err(ReadoutError::Other(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_cpu_features() {
+        let x86 = "processor\t: 0\nflags\t\t: fpu sse4_2 avx2 aes\n\nprocessor\t: 1\nflags\t\t: fpu sse4_2 avx2 aes avx512f\n";
+        let arm = "processor\t: 0\nFeatures\t: fp asimd aes crc32\n";
+
+        assert_eq!(
+            parse_cpu_features(x86),
+            Some(
+                ["aes", "avx2", "avx512f", "fpu", "sse4_2"]
+                    .iter()
+                    .map(|flag| flag.to_string())
+                    .collect()
+            )
+        );
+        assert_eq!(
+            parse_cpu_features(arm),
+            Some(
+                ["aes", "asimd", "crc32", "fp"]
+                    .iter()
+                    .map(|flag| flag.to_string())
+                    .collect()
+            )
+        );
+        assert_eq!(parse_cpu_features("processor\t: 0\n"), None);
+    }
+
+    #[test]
+    fn test_format_shell_path() {
+        let zsh = Path::new("/bin/zsh");
+
+        assert_eq!(
+            format_shell_path(zsh, &ShellFormat::Relative).as_deref(),
+            Some("zsh")
+        );
+        assert_eq!(
+            format_shell_path(zsh, &ShellFormat::Absolute).as_deref(),
+            Some("/bin/zsh")
+        );
+    }
+
+    #[test]
+    fn test_used_memory() {
+        assert_eq!(used_memory(16_000, 4_000, 2_000, 500, 500), 9_000);
+    }
+
+    #[test]
+    fn test_used_memory_underflow() {
+        assert_eq!(used_memory(16_000, 10_000, 4_000, 1_500, 1_000), 0);
+    }
+
+    #[test]
+    fn test_battery_dirs_in() {
+        let power_supply = TempDir::new("power-supply");
+        for (supply, supply_type) in [
+            ("AC", "Mains"),
+            ("ACAD", "Mains"),
+            ("ucsi-source-psy-USBC000:001", "USB"),
+            ("BAT1", "Battery"),
+            ("BAT0", "Battery"),
+        ] {
+            power_supply.write(&format!("{}/type", supply), &format!("{}\n", supply_type));
+        }
+        power_supply.create_dir("hidpp_battery_0");
+
+        assert_eq!(
+            battery_dirs_in(&power_supply),
+            vec![power_supply.join("BAT0"), power_supply.join("BAT1")]
+        );
+    }
+
+    #[test]
+    fn test_battery_health() {
+        let battery = TempDir::new("battery");
+        assert!(matches!(
+            battery_health(&battery),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+
+        battery.write("charge_full_design", "4000000\n");
+        battery.write("charge_full", "3000000\n");
+        assert!(matches!(battery_health(&battery), Ok(75)));
+    }
+
+    #[test]
+    fn test_zram_usage() {
+        let block = TempDir::new("zram");
+        block.create_dir("sda");
+        assert!(matches!(
+            zram_usage(&block),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+
+        block.write("zram0/mm_stat", "1048576 262144 327680 0 327680 12 0 0 0\n");
+        block.write("zram0/disksize", "4294967296\n");
+        block.write("zram1/orig_data_size", "2097152\n");
+        block.write("zram1/disksize", "1073741824\n");
+        assert_eq!(zram_usage(&block).ok(), Some((3072, 5_242_880)));
+    }
+
+    #[test]
+    fn test_battery_technology_and_capacity_design() {
+        let battery = TempDir::new("chemistry");
+        assert!(matches!(
+            battery_technology(&battery),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+        assert!(matches!(
+            battery_capacity_design(&battery),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+
+        battery.write("technology", "LION\n");
+        battery.write("energy_full_design", "57000000\n");
+        battery.write("voltage_min_design", "11400000\n");
+        assert_eq!(battery_technology(&battery).ok().as_deref(), Some("Li-ion"));
+        assert_eq!(battery_capacity_design(&battery).ok(), Some(5_000_000));
+
+        battery.write("technology", "Li-poly\n");
+        battery.write("charge_full_design", "4500000\n");
+        assert_eq!(
+            battery_technology(&battery).ok().as_deref(),
+            Some("Li-poly")
+        );
+        assert_eq!(battery_capacity_design(&battery).ok(), Some(4_500_000));
+    }
+
+    #[test]
+    fn test_combined_battery_percentage_and_state() {
+        let fixture = TempDir::new("batteries");
+        let write = |name: &str, files: &[(&str, &str)]| {
+            for (file, contents) in files {
+                fixture.write(&format!("{}/{}", name, file), contents);
+            }
+            fixture.join(name)
+        };
+
+        let bat0 = write(
+            "BAT0",
+            &[
+                ("capacity", "90\n"),
+                ("charge_now", "45000000\n"),
+                ("charge_full", "50000000\n"),
+                ("status", "Discharging\n"),
+            ],
+        );
+        let bat1 = write(
+            "BAT1",
+            &[
+                ("capacity", "25\n"),
+                ("charge_now", "5000000\n"),
+                ("charge_full", "20000000\n"),
+                ("status", "Charging\n"),
+            ],
+        );
+        let bat2 = write("BAT2", &[("capacity", "40\n"), ("status", "Full\n")]);
+
+        assert_eq!(
+            combined_battery_percentage(std::slice::from_ref(&bat0)).ok(),
+            Some(90)
+        );
+        assert_eq!(
+            combined_battery_percentage(&[bat0.clone(), bat1.clone()]).ok(),
+            Some(71)
+        );
+        assert_eq!(
+            combined_battery_percentage(&[bat0.clone(), bat2.clone()]).ok(),
+            Some(65)
+        );
+        assert!(matches!(
+            combined_battery_state(&[bat0.clone(), bat1]),
+            Ok(BatteryState::Charging)
+        ));
+        assert!(matches!(
+            combined_battery_state(&[bat2.clone(), bat0]),
+            Ok(BatteryState::Discharging)
+        ));
+        assert!(matches!(
+            combined_battery_state(&[bat2]),
+            Ok(BatteryState::Full)
+        ));
+        assert!(matches!(
+            combined_battery_state(&[]),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+    }
+
+    #[test]
+    fn test_battery_power_draw() {
+        let battery = TempDir::new("power");
+        assert!(matches!(
+            battery_power_draw(&battery, BatteryState::Discharging),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+
+        battery.write("current_now", "-1500000\n");
+        battery.write("voltage_now", "12000000\n");
+        assert_eq!(
+            battery_power_draw(&battery, BatteryState::Discharging).ok(),
+            Some(-18.0)
+        );
+
+        battery.write("power_now", "9500000\n");
+        assert_eq!(
+            battery_power_draw(&battery, BatteryState::Charging).ok(),
+            Some(9.5)
+        );
+    }
+
+    #[test]
+    fn test_battery_voltage_and_temperature() {
+        let battery = TempDir::new("health");
+        battery.write("voltage_now", "3850000\n");
+        assert_eq!(battery_voltage(&battery).ok(), Some(3.85));
+        assert!(matches!(
+            battery_temperature(&battery),
+            Err(ReadoutError::MetricNotAvailable)
+        ));
+
+        battery.write("temp", "-35\n");
+        assert_eq!(battery_temperature(&battery).ok(), Some(-3.5));
+    }
+
+    #[test]
+    fn test_hypervisor_from_dmi() {
+        assert_eq!(
+            hypervisor_from_dmi("QEMU", "Standard PC (Q35 + ICH9, 2009)"),
+            Some("qemu")
+        );
+        assert_eq!(
+            hypervisor_from_dmi("innotek GmbH", "VirtualBox"),
+            Some("oracle")
+        );
+        assert_eq!(
+            hypervisor_from_dmi("Microsoft Corporation", "Virtual Machine"),
+            Some("microsoft")
+        );
+        assert_eq!(hypervisor_from_dmi("LENOVO", "20KHCTO1WW"), None);
+    }
+
+    #[test]
+    fn test_parse_cpu_model_name() {
+        let cpuinfo = "processor\t: 0\nvendor_id\t: GenuineIntel\nmodel name\t: Intel(R)  Core(TM) i5-8265U CPU @ 1.60GHz \n\nprocessor\t: 1\nmodel name\t: Intel(R)  Core(TM) i5-8265U CPU @ 1.60GHz\n";
+        assert_eq!(
+            parse_cpu_model_name(cpuinfo).as_deref(),
+            Some("Intel(R) Core(TM) i5-8265U CPU @ 1.60GHz")
+        );
+        assert_eq!(
+            parse_cpu_model_name("processor\t: 0\nBogoMIPS\t: 38.40\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        let timeout = std::time::Duration::from_millis(200);
+
+        let output = run_with_timeout(Command::new("echo").arg("hello"), timeout).unwrap();
+        assert_eq!(output.stdout, b"hello\n");
+
+        assert!(run_with_timeout(Command::new("sleep").arg("5"), timeout).is_none());
+        assert!(
+            run_with_timeout(&mut Command::new("libmacchina-missing-binary"), timeout).is_none()
+        );
+    }
+
+    #[test]
+    fn test_count_packages() {
+        let packages = count_packages(vec![
+            (PackageManager::Rpm, || Some(0)),
+            (PackageManager::Flatpak, || None),
+            (PackageManager::Dpkg, || Some(1200)),
+        ]);
+
+        assert_eq!(packages.len(), 1);
+        assert!(matches!(packages[0], (PackageManager::Dpkg, 1200)));
+    }
+
+    #[test]
+    fn test_physical_cores_from_cpuinfo() {
+        // A big.LITTLE SoC with four Cortex-A55 and four Cortex-A76 cores, whose
+        // /proc/cpuinfo doesn't report any physical or core ID.
+        let big_little: String = (0..8)
+            .map(|processor| {
+                let part = if processor < 4 { "0xd05" } else { "0xd0b" };
+                format!(
+                    "processor\t: {}\nBogoMIPS\t: 38.40\nCPU implementer\t: 0x41\nCPU part\t: {}\n\n",
+                    processor, part
+                )
+            })
+            .collect();
+        assert_eq!(physical_cores_from_cpuinfo(&big_little), Some(8));
+
+        // Two cores with two threads each.
+        let smt: String = [(0, 0), (1, 1), (2, 0), (3, 1)]
+            .iter()
+            .map(|(processor, core)| {
+                format!(
+                    "processor\t: {}\nphysical id\t: 0\ncore id\t\t: {}\n\n",
+                    processor, core
+                )
+            })
+            .collect();
+        assert_eq!(physical_cores_from_cpuinfo(&smt), Some(2));
+
+        assert_eq!(physical_cores_from_cpuinfo(""), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_count_portage() {
+        let db = TempDir::new("portage");
+        for package in [
+            "app-editors/vim-9.0.1677",
+            "app-editors/nano-7.2",
+            "sys-libs/-MERGING-zlib-1.3",
+        ] {
+            db.create_dir(package);
+        }
+        db.write("sys-libs/glibc-2.37-r7/SLOT", "2.2\n");
+        db.write(".keep", "");
+
+        assert_eq!(count_portage(&db), Some(3));
+        assert_eq!(count_portage(&db.join("missing")), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_count_xbps() {
+        let fixture = TempDir::new("xbps");
+        let pkgdb = fixture.write(
+            "pkgdb-0.38.plist",
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple Computer//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+	<key>base-files</key>
+	<dict>
+		<key>automatic-install</key>
+		<false/>
+		<key>install-date</key>
+		<string>2023-10-01 12:00 UTC</string>
+		<key>pkgver</key>
+		<string>base-files-0.143_1</string>
+		<key>state</key>
+		<string>installed</string>
+	</dict>
+	<key>glibc</key>
+	<dict>
+		<key>install-date</key>
+		<string>2023-10-01 12:00 UTC</string>
+		<key>pkgver</key>
+		<string>glibc-2.36_2</string>
+		<key>state</key>
+		<string>installed</string>
+	</dict>
+	<key>linux6.5</key>
+	<dict>
+		<key>pkgver</key>
+		<string>linux6.5-6.5.6_1</string>
+		<key>state</key>
+		<string>unpacked</string>
+	</dict>
+</dict>
+</plist>
+"#,
+        );
+
+        assert_eq!(count_xbps(&pkgdb), Some(2));
+        assert_eq!(count_xbps(&pkgdb.with_extension("missing")), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_count_pacman() {
+        let local_dir = TempDir::new("pacman");
+        for package in ["bash-5.2.026-2", "linux-6.9.7.arch1-1"] {
+            local_dir.create_dir(package);
+        }
+        local_dir.write("ALPM_DB_VERSION", "9\n");
+
+        assert_eq!(count_pacman(&local_dir), Some(2));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_count_snaps() {
+        let fixture = TempDir::new("snaps");
+        let snap_root = fixture.join("snap");
+        let snaps_dir = fixture.create_dir("snaps");
+
+        for dir in ["bin", "core22", "firefox", "snapd"] {
+            fixture.create_dir(&format!("snap/{}", dir));
+        }
+        fixture.write("snap/README", "");
+        for image in [
+            "core22_1380.snap",
+            "firefox_4173.snap",
+            "firefox_4259.snap",
+            "vlc_3078.snap",
+            "partial",
+        ] {
+            fixture.write(&format!("snaps/{}", image), "");
+        }
+
+        assert_eq!(count_snaps(&snap_root, &snaps_dir, false), Some(4));
+        assert_eq!(count_snaps(&snap_root, &snaps_dir, true), Some(2));
+        assert_eq!(
+            count_snaps(&fixture.join("missing"), &fixture.join("missing"), false),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    fn test_normalize_mac_address() {
+        assert_eq!(
+            normalize_mac_address("3C:7C:3F:1E:A2:90\n"),
+            Some(String::from("3c:7c:3f:1e:a2:90"))
+        );
+        assert_eq!(
+            normalize_mac_address("3c-7c-3f-1e-a2-9"),
+            Some(String::from("3c:7c:3f:1e:a2:09"))
+        );
+        assert_eq!(normalize_mac_address("00:00:00:00:00:00"), None);
+        assert_eq!(normalize_mac_address("3c:7c:3f:1e:a2"), None);
+        assert_eq!(normalize_mac_address("not a mac"), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_host_model() {
+        let fixture = TempDir::new("dmi");
+        let write = |dir: &str, attributes: &[(&str, &str)]| {
+            for (attribute, value) in attributes {
+                fixture.write(&format!("{}/{}", dir, attribute), &format!("{}\n", value));
+            }
+            fixture.join(dir)
+        };
+
+        let lenovo = write(
+            "lenovo",
+            &[
+                ("sys_vendor", "LENOVO"),
+                ("product_name", "20XW0055US"),
+                ("product_version", "ThinkPad X1 Carbon Gen 9"),
+            ],
+        );
+        let dell = write(
+            "dell",
+            &[
+                ("sys_vendor", "Dell Inc."),
+                ("product_name", "XPS 13 9310"),
+                ("product_version", ""),
+            ],
+        );
+        let custom = write(
+            "custom",
+            &[
+                ("sys_vendor", "System manufacturer"),
+                ("product_name", "System Product Name"),
+                ("product_version", "System Version"),
+                ("board_name", "ROG STRIX B550-F GAMING"),
+            ],
+        );
+        let empty = write("empty", &[("product_name", "To be filled by O.E.M.")]);
+
+        let models = [
+            host_model(&lenovo).ok(),
+            host_model(&dell).ok(),
+            host_model(&custom).ok(),
+            host_model(&empty).ok(),
+        ];
+
+        assert_eq!(
+            models,
+            [
+                Some(String::from("LENOVO ThinkPad X1 Carbon Gen 9 (20XW0055US)")),
+                Some(String::from("Dell Inc. XPS 13 9310")),
+                Some(String::from("ROG STRIX B550-F GAMING")),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jiffies_usage() {
+        let before = parse_jiffies("cpu  4705 356 584 3699 23 23 0 0 0 0");
+        let after = parse_jiffies("cpu  4805 356 634 3749 23 23 0 0 0 0");
+
+        assert_eq!(before, (3722, 9390));
+        assert_eq!(
+            parse_jiffies("cpu  4705 356 584 3699 23 23 0 0 1200 300"),
+            (3722, 9390)
+        );
+        assert_eq!(jiffies_usage(before, after), 75);
+        assert_eq!(jiffies_usage(after, after), 0);
+    }
+
+    #[test]
+    fn test_accent_color_parsers() {
+        let kdeglobals = "[Colors:View]\nAccentColor=1,2,3\n\n[General]\nColorScheme=BreezeDark\nAccentColor=61,174,233\n";
+        let overlays = r#"{"android.theme.customization.system_palette":"FF1B6EF3","android.theme.customization.theme_style":"TONAL_SPOT"}"#;
+
+        assert_eq!(parse_kde_accent_color(kdeglobals), Some((61, 174, 233)));
+        assert_eq!(
+            parse_kde_accent_color("[General]\nAccentColor=300,0,0"),
+            None
+        );
+        assert_eq!(gnome_accent_color("teal"), Some((0x21, 0x90, 0xa4)));
+        assert_eq!(gnome_accent_color("magenta"), None);
+        assert_eq!(
+            parse_android_accent_color(overlays),
+            Some((0x1b, 0x6e, 0xf3))
+        );
+        assert_eq!(parse_android_accent_color("{}"), None);
+        assert_eq!(
+            parse_android_accent_color(r#"{"android.theme.customization.accent_color":"FFAé1B6"}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_cpu_vendor() {
+        let x86 = "processor\t: 0\nvendor_id\t: AuthenticAMD\ncpu family\t: 25\nmodel name\t: AMD Ryzen 7 5800X 8-Core Processor\n";
+        let arm = "processor\t: 0\nBogoMIPS\t: 38.40\nFeatures\t: fp asimd\nCPU implementer\t: 0x51\nCPU architecture: 8\n";
+        let unknown = "processor\t: 0\nCPU implementer\t: 0x99\n";
+
+        assert_eq!(parse_cpu_vendor(x86).as_deref(), Some("AuthenticAMD"));
+        assert_eq!(parse_cpu_vendor(arm).as_deref(), Some("Qualcomm"));
+        assert_eq!(parse_cpu_vendor(unknown).as_deref(), Some("0x99"));
+        assert_eq!(parse_cpu_vendor("processor\t: 0\n"), None);
+    }
+
+    #[test]
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    fn test_count_homebrew() {
+        let fixture = TempDir::new("homebrew");
+        let (arm, intel) = (fixture.join("opt/homebrew"), fixture.join("usr/local"));
+        for package in [
+            "opt/homebrew/Cellar/git",
+            "opt/homebrew/Cellar/ripgrep",
+            "opt/homebrew/Caskroom/firefox",
+            "usr/local/Cellar/python@3.12",
+        ] {
+            fixture.create_dir(package);
+        }
+        fixture.write("opt/homebrew/Cellar/.keepme", "");
+
+        assert_eq!(count_homebrew(std::slice::from_ref(&arm)), Some(3));
+        assert_eq!(count_homebrew(&[arm, intel]), Some(4));
+        assert_eq!(count_homebrew(&[]), None);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_count_appimages() {
+        let fixture = TempDir::new("appimage");
+        let applications = fixture.create_dir("Applications");
+        let bin = fixture.create_dir("bin");
+
+        let krita = fixture.write("Applications/Krita-5.2.2-x86_64.AppImage", "");
+        fixture.write("Applications/Obsidian-1.5.3.appimage", "");
+        fixture.write("Applications/notes.txt", "");
+        std::os::unix::fs::symlink(krita, bin.join("krita.AppImage")).unwrap();
+
+        assert_eq!(
+            count_appimages(&[applications.clone(), bin.clone(), applications]),
+            Some(2)
+        );
+        assert_eq!(count_appimages(&[bin.join("missing")]), None);
+    }
+
+    #[test]
+    fn test_parse_version_output() {
+        assert_eq!(
+            parse_version_output("GNOME Shell 45.2\n").as_deref(),
+            Some("45.2")
+        );
+        assert_eq!(
+            parse_version_output("plasmashell 5.27.10\n").as_deref(),
+            Some("5.27.10")
+        );
+        assert_eq!(
+            parse_version_output("xfce4-session 4.18.3 (Xfce 4.18)\n\nCopyright (c) 2003-2023\n")
+                .as_deref(),
+            Some("4.18.3")
+        );
+        assert_eq!(parse_version_output("unknown option\n"), None);
+    }
+
+    #[test]
+    fn test_parse_modules() {
+        let modules = "snd_hda_intel 57344 3 - Live 0x0000000000000000\nbtusb 77824 0 - Live 0x0000000000000000\n\nnvme 57344 2 - Live 0x0000000000000000 (E)\n";
+
+        assert_eq!(parse_modules(modules), ["btusb", "nvme", "snd_hda_intel"]);
+        assert!(parse_modules("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_meminfo_value() {
+        let meminfo = "MemTotal:       16318600 kB\nSwapCached:        12 kB\nCached:  4096 kB\n  Buffers :\t512 kB\nMemAvailable:";
+
+        assert_eq!(
+            parse_meminfo_value(meminfo, "MemTotal").ok(),
+            Some(16318600)
+        );
+        assert_eq!(parse_meminfo_value(meminfo, "Cached").ok(), Some(4096));
+        assert_eq!(parse_meminfo_value(meminfo, "SwapCached").ok(), Some(12));
+        assert_eq!(parse_meminfo_value(meminfo, "Buffers").ok(), Some(512));
+        assert_eq!(parse_meminfo_value(meminfo, "MemAvailable").ok(), None);
+        assert_eq!(parse_meminfo_value(meminfo, "MemFree").ok(), None);
+        assert_eq!(parse_meminfo_value(meminfo, "Mem").ok(), None);
+    }
+
+    #[test]
+    fn test_parse_default_interface() {
+        let route_table = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+tun0\t00000000\t00000000\t0001\t0\t0\t0\t00000080\t0\t0\t0
+tun0\t00000080\t00000000\t0001\t0\t0\t0\t00000080\t0\t0\t0
+rmnet0\t00000000\t0100000A\t0003\t0\t0\t700\t00000000\t0\t0\t0
+wlan0\t00000000\t0101A8C0\t0003\t0\t0\t600\t00000000\t0\t0\t0
+wlan0\t0001A8C0\t00000000\t0001\t0\t0\t600\t00FFFFFF\t0\t0\t0
+";
+
+        assert_eq!(
+            parse_default_interface(route_table),
+            Some(String::from("wlan0"))
+        );
+        assert_eq!(
+            parse_default_interface(route_table.lines().next().unwrap()),
+            None
+        );
+    }
 }
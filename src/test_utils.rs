@@ -0,0 +1,66 @@
+//! Fixtures shared by the unit tests.
+use std::env;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// An empty directory below the system's temporary directory, which is removed along with
+/// its contents when dropped, even if the test using it panics.
+pub(crate) struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Creates a directory whose name is made of `name`, the PID and a counter, so that
+    /// neither concurrent tests nor concurrent test runs share it.
+    pub(crate) fn new(name: &str) -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = env::temp_dir().join(format!(
+            "libmacchina-{}-{}-{}",
+            name,
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+
+        TempDir(path)
+    }
+
+    /// Creates the directory `relative` to this one, along with its parents.
+    pub(crate) fn create_dir(&self, relative: &str) -> PathBuf {
+        let path = self.0.join(relative);
+        fs::create_dir_all(&path).unwrap();
+        path
+    }
+
+    /// Writes `contents` to the file `relative` to this directory, creating its parents.
+    pub(crate) fn write(&self, relative: &str, contents: &str) -> PathBuf {
+        let path = self.0.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, contents).unwrap();
+        path
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for TempDir {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
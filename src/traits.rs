@@ -3,9 +3,13 @@
 #![allow(unused_variables)]
 
 use byte_unit::AdjustedByte;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// This enum contains possible error types when doing sensor & variable readouts.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReadoutError {
     /// A specific metric might not be available on all systems (e. g. battery percentage on a
     /// desktop). \
@@ -16,9 +20,26 @@ pub enum ReadoutError {
     /// unsatisfied requirements.
     Other(String),
 
+    /// The file backing a readout exists, or is expected to exist, but could not be read,
+    /// _e.g._ because of missing permissions.
+    FileRead {
+        path: PathBuf,
+        #[cfg_attr(feature = "serde", serde(with = "io_error_serde"))]
+        source: std::io::Error,
+    },
+
     /// Getting a readout on a specific operating system might not make sense or causes some other
     /// kind of warning. This is not necessarily an error.
     Warning(String),
+
+    /// The readout is not implemented for this platform (yet). This is what the default
+    /// implementations of the trait methods return, so that methods can be added to a trait
+    /// without every platform having to implement them right away.
+    NotImplemented,
+
+    /// A readout that depends on an external resource, _e.g._ a network service, did not
+    /// complete in time.
+    Timeout,
 }
 
 impl ToString for ReadoutError {
@@ -28,23 +49,61 @@ impl ToString for ReadoutError {
                 String::from("Metric is not available on this system.")
             }
             ReadoutError::Other(s) => s.clone(),
+            ReadoutError::FileRead { path, source } => {
+                format!("Failed to read {}: {}", path.display(), source)
+            }
             ReadoutError::Warning(s) => s.clone(),
+            ReadoutError::NotImplemented => String::from(
+                "This metric is not available on this platform or is not yet implemented by Macchina.",
+            ),
+            ReadoutError::Timeout => String::from("The readout timed out."),
+        }
+    }
+}
+
+impl Clone for ReadoutError {
+    fn clone(&self) -> Self {
+        match self {
+            ReadoutError::MetricNotAvailable => ReadoutError::MetricNotAvailable,
+            ReadoutError::Other(s) => ReadoutError::Other(s.clone()),
+            // `std::io::Error` is not `Clone`, so only its kind and message are kept.
+            ReadoutError::FileRead { path, source } => ReadoutError::FileRead {
+                path: path.clone(),
+                source: std::io::Error::new(source.kind(), source.to_string()),
+            },
+            ReadoutError::Warning(s) => ReadoutError::Warning(s.clone()),
+            ReadoutError::NotImplemented => ReadoutError::NotImplemented,
+            ReadoutError::Timeout => ReadoutError::Timeout,
         }
     }
 }
 
+/// (De)serializes the `std::io::Error` of `ReadoutError::FileRead` as its message.
+#[cfg(feature = "serde")]
+mod io_error_serde {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        error: &std::io::Error,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&error.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<std::io::Error, D::Error> {
+        let message = String::deserialize(deserializer)?;
+        Ok(std::io::Error::new(std::io::ErrorKind::Other, message))
+    }
+}
+
 impl From<&ReadoutError> for ReadoutError {
     fn from(r: &ReadoutError) -> Self {
         r.to_owned()
     }
 }
 
-lazy_static! {
-    static ref STANDARD_NO_IMPL: ReadoutError = ReadoutError::Warning(String::from(
-        "This metric is not available on this platform or is not yet implemented by Macchina."
-    ));
-}
-
 /**
 This trait provides the necessary functions for querying battery statistics from the host
 computer. A desktop computer might not be able to provide values such as `percentage` and
@@ -80,24 +139,83 @@ impl BatteryReadout for MacOSBatteryReadout {
 */
 pub trait BatteryReadout {
     /// Creates a new instance of the structure which implements this trait.
-    fn new() -> Self;
+    fn new() -> Self
+    where
+        Self: Sized;
 
     /// This function is used for querying the current battery percentage. The expected value is
     /// a u8 in the range of `0` to `100`.
     fn percentage(&self) -> Result<u8, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the percentage of every battery of the host, as
+    /// `(name, percentage)` pairs. Hosts without batteries should return an empty `Vec`.
+    fn percentage_all(&self) -> Result<Vec<(String, u8)>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function is used for querying the current battery charging state. If the battery is
-    /// currently being charged, we expect a return value of `BatteryState::Charging`, otherwise
-    /// `BatteryState::Discharging`.
+    /// currently being charged, we expect a return value of `BatteryState::Charging`. Fully charged
+    /// batteries should be reported as `BatteryState::Full`, and those of plugged in devices that
+    /// are not being charged as `BatteryState::NotCharging`, otherwise `BatteryState::Discharging`.
     fn status(&self) -> Result<BatteryState, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
-    /// This function is used for querying the current battery's health in percentage.
-    fn health(&self) -> Result<u64, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+    /// This function is used for querying the current battery's health, _i.e._ its full charge
+    /// capacity as a percentage of its design capacity. The expected value is a u8 in the range
+    /// of `0` to `100`.
+    fn health(&self) -> Result<u8, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the chemistry of the current battery, _e.g._
+    /// `Li-ion` or `Li-poly`.
+    fn technology(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the charge the current battery was designed to
+    /// hold when new, in µAh.
+    fn capacity_design(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the estimated time in minutes until the battery is
+    /// empty, or until it is fully charged if it is currently being charged.
+    fn time_remaining(&self) -> Result<u32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the power in watts currently flowing into the
+    /// battery. The value is positive while the battery is being charged and negative while
+    /// it is being discharged, _e.g._ `-7.4` for a laptop drawing 7.4 watts from its battery.
+    fn power_draw(&self) -> Result<f32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the current voltage of the battery in volts,
+    /// _e.g._ `3.85`.
+    fn voltage(&self) -> Result<f32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the temperature of the battery in degrees
+    /// Celsius, _e.g._ `31.5`.
+    fn temperature(&self) -> Result<f32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function is used for querying the percentage, charging state and health of the
+    /// battery in one go. Implementations should read the underlying values only once, so that
+    /// the returned `BatteryInfo` is a consistent snapshot.
+    fn all(&self) -> Result<BatteryInfo, ReadoutError> {
+        Ok(BatteryInfo {
+            percentage: self.percentage()?,
+            state: self.status()?,
+            health: self.health().ok(),
+        })
     }
 }
 
@@ -132,16 +250,44 @@ impl KernelReadout for MacOSKernelReadout {
 */
 pub trait KernelReadout {
     /// Creates a new instance of the structure which implements this trait.
-    fn new() -> Self;
+    fn new() -> Self
+    where
+        Self: Sized;
 
     /// This function should return the version of the kernel (e. g. `20.3.0` on macOS for Darwin).
     fn os_release(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the kernel name as a string (e. g. `Darwin` on macOS).
     fn os_type(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the hardware architecture of the host (e. g. `x86_64` or
+    /// `aarch64`), as reported by `uname -m`.
+    fn architecture(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name and version of the system's C library
+    /// (e. g. `glibc 2.38` or `musl 1.2.4`).
+    fn libc_version(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the hostname the kernel reports for the host, as returned
+    /// by `uname -n` (e. g. `supercomputer`).
+    fn hostname(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function returns the kernel name and version, as returned by `os_type()` and
+    /// `os_release()`, separated by a space. Errors of either of them are passed on.
+    ///
+    /// _e.g._ `Linux 6.6.2-arch1-1`
+    fn version(&self) -> Result<String, ReadoutError> {
+        Ok(format!("{} {}", self.os_type()?, self.os_release()?))
     }
 
     /// This function is used for getting the kernel name and version in a pretty format.
@@ -189,36 +335,107 @@ impl MemoryReadout for MacOSMemoryReadout {
 */
 pub trait MemoryReadout {
     /// Creates a new instance of the structure which implements this trait.
-    fn new() -> Self;
+    fn new() -> Self
+    where
+        Self: Sized;
 
     /// This function should return the total available memory in kilobytes.
     fn total(&self) -> Result<u64, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the free available memory in kilobytes.
+    ///
+    /// This is memory that isn't used for anything at all, see `available()` for the amount of
+    /// memory that can be handed out to applications.
     fn free(&self) -> Result<u64, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return an estimate of the memory available for starting new
+    /// applications without swapping, in kilobytes.
+    ///
+    /// Unlike `free()`, this accounts for the page cache and reclaimable kernel memory, which
+    /// the kernel can drop on demand, making it the better figure for displaying memory pressure.
+    fn available(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the current memory value for buffers in kilobytes.
     fn buffers(&self) -> Result<u64, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the amount of cached content in memory in kilobytes.
     fn cached(&self) -> Result<u64, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the amount of reclaimable memory in kilobytes.
     fn reclaimable(&self) -> Result<u64, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the amount of currently used memory in kilobytes.
     fn used(&self) -> Result<u64, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function returns the percentage of memory currently in use, based on `used()` and
+    /// `total()`.
+    ///
+    /// _e.g._ `42.5`
+    fn used_percentage(&self) -> Result<f32, ReadoutError> {
+        let total = self.total()?;
+        if total == 0 {
+            return Err(ReadoutError::Other(String::from(
+                "The total amount of memory is reported as zero.",
+            )));
+        }
+
+        Ok(self.used()? as f32 / total as f32 * 100.0)
+    }
+
+    /// This function should return the total amount of swap space in kilobytes.
+    fn swap_total(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the amount of currently used swap space in kilobytes.
+    fn swap_used(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function returns `total()` converted to the given `unit`.
+    fn total_in(&self, unit: MemoryUnit) -> Result<u64, ReadoutError> {
+        Ok(unit.from_kib(self.total()?))
+    }
+
+    /// This function returns `free()` converted to the given `unit`.
+    fn free_in(&self, unit: MemoryUnit) -> Result<u64, ReadoutError> {
+        Ok(unit.from_kib(self.free()?))
+    }
+
+    /// This function returns `available()` converted to the given `unit`.
+    fn available_in(&self, unit: MemoryUnit) -> Result<u64, ReadoutError> {
+        Ok(unit.from_kib(self.available()?))
+    }
+
+    /// This function returns `used()` converted to the given `unit`.
+    fn used_in(&self, unit: MemoryUnit) -> Result<u64, ReadoutError> {
+        Ok(unit.from_kib(self.used()?))
+    }
+
+    /// This function should return the amount of data stored in compressed RAM-based swap
+    /// devices (zram) in kilobytes, measured before compression.
+    fn zram_used(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the total capacity of compressed RAM-based swap devices
+    /// (zram) in kilobytes, _i.e._ the amount of uncompressed data they can hold.
+    fn zram_total(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 }
 
@@ -248,7 +465,9 @@ impl PackageReadout for MacOSPackageReadout {
 */
 pub trait PackageReadout {
     /// Creates a new instance of the structure which implements this trait.
-    fn new() -> Self;
+    fn new() -> Self
+    where
+        Self: Sized;
 
     /// This function should return the number of installed packages.
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
@@ -289,7 +508,9 @@ impl ProductReadout for MacOSProductReadout {
 */
 pub trait ProductReadout {
     /// Creates a new instance of the structure which implements this trait.
-    fn new() -> Self;
+    fn new() -> Self
+    where
+        Self: Sized;
 
     /// This function should return the vendor name of the host's machine.
     ///
@@ -297,7 +518,7 @@ pub trait ProductReadout {
     ///
     /// This is set by the machine's manufacturer.
     fn vendor(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the family name of the host's machine.
@@ -306,7 +527,7 @@ pub trait ProductReadout {
     ///
     /// This is set by the machine's manufacturer.
     fn family(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the product name of the host's machine.
@@ -315,7 +536,201 @@ pub trait ProductReadout {
     ///
     /// This is set by the machine's manufacturer.
     fn product(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the serial number of the machine.
+    ///
+    /// _e.g._ `PF2XK3D9`
+    ///
+    /// Most platforms only expose the serial number to privileged users. If it can not be
+    /// read because of missing permissions, `ReadoutError::MetricNotAvailable` is returned,
+    /// so callers can tell this case apart from other failures and ask for elevation.
+    fn serial_number(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the class of the device, one of `phone`, `tablet`, `tv`,
+    /// `watch` or `automotive`, which lets user interfaces adapt their layout to it.
+    ///
+    /// _e.g._ `tablet`
+    fn form_factor(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the fingerprint of the installed system build, which
+    /// identifies the exact ROM a device runs.
+    ///
+    /// _e.g._ `google/oriole/oriole:14/UQ1A.240205.004/11269751:user/release-keys`
+    fn build_fingerprint(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the identifier of the installed system build.
+    ///
+    /// _e.g._ `UQ1A.240205.004`
+    fn build_id(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+}
+
+/**
+This trait provides the interface for implementing functionality used for querying information
+about the _graphics processors_ of the host machine.
+
+# Example
+
+```
+use libmacchina::traits::GpuReadout;
+use libmacchina::traits::ReadoutError;
+
+pub struct MacOSGpuReadout;
+
+impl GpuReadout for MacOSGpuReadout {
+    fn new() -> Self {
+        MacOSGpuReadout {}
+    }
+
+    fn model(&self) -> Result<Vec<String>, ReadoutError> {
+        Ok(vec![String::from("Apple M1")])
+    }
+}
+```
+*/
+pub trait GpuReadout {
+    /// Creates a new instance of the structure which implements this trait.
+    fn new() -> Self
+    where
+        Self: Sized;
+
+    /// This function should return the model name of every GPU (integrated and discrete)
+    /// found on the host's machine, e.g. both GPUs of a laptop using NVIDIA Optimus.
+    ///
+    /// _e.g._ `["Intel Corporation UHD Graphics 620", "NVIDIA Corporation GP108M [GeForce MX150]"]`
+    fn model(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the total amount of video memory of every GPU in bytes, in
+    /// the same order as `model()`. GPUs whose video memory can't be determined, _e.g._
+    /// integrated GPUs that share the system's memory, are reported as `0`.
+    ///
+    /// _e.g._ `[0, 2147483648]`
+    fn vram_total(&self) -> Result<Vec<u64>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+}
+
+/**
+This trait provides the interface for implementing functionality used for querying the _disk
+usage_ of a mounted filesystem on the host machine.
+
+# Example
+
+```
+use libmacchina::traits::DiskReadout;
+use libmacchina::traits::ReadoutError;
+use std::path::Path;
+
+pub struct MacOSDiskReadout;
+
+impl DiskReadout for MacOSDiskReadout {
+    fn new() -> Self {
+        MacOSDiskReadout {}
+    }
+
+    fn total(&self, path: &Path) -> Result<u64, ReadoutError> {
+        // Get the size of the filesystem mounted at the given path.
+        Ok(512 * 1024 * 1024 * 1024) // Return 512GB in bytes.
+    }
+
+    fn free(&self, path: &Path) -> Result<u64, ReadoutError> {
+        // Get the space that is still available on the filesystem.
+        Ok(120 * 1024 * 1024 * 1024) // Return 120GB in bytes.
+    }
+}
+```
+*/
+pub trait DiskReadout {
+    /// Creates a new instance of the structure which implements this trait.
+    fn new() -> Self
+    where
+        Self: Sized;
+
+    /// This function should return the total size in bytes of the filesystem mounted at `path`,
+    /// _e.g._ `/` for the root filesystem.
+    fn total(&self, path: &Path) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the space in bytes that is available to unprivileged users
+    /// on the filesystem mounted at `path`.
+    fn free(&self, path: &Path) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+}
+
+/**
+This trait provides the interface for implementing functionality used for querying the amount of
+data that has been transferred through a _network interface_ of the host machine.
+
+# Example
+
+```
+use libmacchina::traits::NetworkReadout;
+use libmacchina::traits::ReadoutError;
+
+pub struct MacOSNetworkReadout;
+
+impl NetworkReadout for MacOSNetworkReadout {
+    fn new() -> Self {
+        MacOSNetworkReadout {}
+    }
+
+    fn tx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        // Get the number of bytes sent through the given interface.
+        Ok(1024 * 1024) // Return 1MB.
+    }
+
+    fn rx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        // Get the number of bytes received through the given interface.
+        Ok(8 * 1024 * 1024) // Return 8MB.
+    }
+}
+```
+*/
+pub trait NetworkReadout {
+    /// Creates a new instance of the structure which implements this trait.
+    fn new() -> Self
+    where
+        Self: Sized;
+
+    /// This function should return the number of bytes transmitted through `interface`,
+    /// or through the interface carrying the default route if `interface` is `None`.
+    fn tx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of bytes received through `interface`,
+    /// or through the interface carrying the default route if `interface` is `None`.
+    fn rx_bytes(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the hardware (MAC) address of `interface`, or of the
+    /// primary interface if `interface` is `None`.
+    ///
+    /// _e.g._ `3c:7c:3f:1e:a2:90`
+    fn mac_address(&self, interface: Option<String>) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the negotiated link speed of `interface` in Mbps, or of the
+    /// interface carrying the default route if `interface` is `None`.
+    ///
+    /// _e.g._ `1000`
+    fn link_speed(&self, interface: Option<String>) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 }
 
@@ -349,41 +764,52 @@ impl GeneralReadout for MacOSGeneralReadout {
 */
 pub trait GeneralReadout {
     /// Creates a new instance of the structure which implements this trait.
-    fn new() -> Self;
+    fn new() -> Self
+    where
+        Self: Sized;
 
     /// This function should return the backlight (brightness) value of the machine.
     ///
     /// _e.g._ `100`
     fn backlight(&self) -> Result<usize, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
-    /// This function should return the display resolution of the machine.
+    /// This function should return the resolution of every connected display of the machine.
     ///
-    /// _e.g. `1920x1080`
-    fn resolution(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+    /// _e.g._ `["1920x1080", "2560x1440"]`
+    fn resolution(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the username of the currently logged on user.
     ///
     /// _e.g._ `johndoe`
     fn username(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the hostname of the host's computer.
     ///
     /// _e.g._ `supercomputer`
     fn hostname(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the name of the distribution of the operating system.
     ///
     /// _e.g._ `Arch Linux`
     fn distribution(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return a stable, machine-readable identifier of the operating
+    /// system, which fetch tools can use to pick the logo to draw. On Linux, this is the `ID`
+    /// of `/etc/os-release`, or the first entry of its `ID_LIKE` if `ID` is not set.
+    ///
+    /// _e.g._ `arch`
+    fn os_logo_id(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the user's local ip address of the
@@ -391,35 +817,70 @@ pub trait GeneralReadout {
     ///
     /// _e.g._ `192.168.1.11`
     fn local_ip(&self, interface: Option<String>) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return every non-loopback IPv4 and IPv6 address of the specified
+    /// interface, or of all interfaces if `interface` is `None`.
+    ///
+    /// _e.g._ `[192.168.1.11, fe80::1c2f:5aff:fe3b:9d41]`
+    fn local_ips(&self, interface: Option<String>) -> Result<Vec<IpAddr>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function queries an HTTP echo service for the public IP address of the host,
+    /// _i.e._ the address other hosts on the internet see its connections coming from.
+    ///
+    /// Unlike every other readout, this one is not local: it sends a request to `endpoint`,
+    /// which defaults to `https://api.ipify.org`, and is therefore only available with the
+    /// `network` feature. The endpoint is expected to answer with the bare address. If it does
+    /// not answer within 5 seconds, `ReadoutError::Timeout` is returned.
+    ///
+    /// _e.g._ `203.0.113.7`
+    #[cfg(feature = "network")]
+    fn public_ip(&self, endpoint: Option<String>) -> Result<IpAddr, ReadoutError> {
+        crate::shared::public_ip(endpoint.as_deref().unwrap_or("https://api.ipify.org"))
     }
 
     /// This function should return the name of the used desktop environment.
     ///
     /// _e.g._ `Plasma`
     fn desktop_environment(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the version of the used desktop environment.
+    ///
+    /// _e.g._ `5.27.10`
+    fn desktop_environment_version(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the type of session that's in use.
     ///
     /// _e.g._ `Wayland`
     fn session(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the display server of the session in lowercase, _i.e._
+    /// `wayland` or `x11`, or `tty` when no display server is in use.
+    fn session_type(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the name of the used window manager.
     ///
     /// _e.g._ `KWin`
     fn window_manager(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the name of the used terminal emulator.
     ///
     /// _e.g._ `kitty`
     fn terminal(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /**
@@ -432,65 +893,314 @@ pub trait GeneralReadout {
     - If `_shorthand` is `ShellFormat::Absolute` the absolute path of the shell will be returned.
 
     _e.g._ /bin/bash, /bin/zsh, etc.
+
+    - If `kind` is `ShellKind::Default`, the login shell of the current user, as found in the
+      user database, _e.g._ `/etc/passwd`, is returned, regardless of the `SHELL` environment
+      variable, which is inherited through `sudo`, `su` or cron and is thus unreliable.
     */
 
     fn shell(&self, _shorthand: ShellFormat, kind: ShellKind) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the model name of the CPU \
     ///
     /// _e.g._ `Intel(R) Core(TM) i5-8265U CPU @ 1.60GHz`
     fn cpu_model_name(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function returns the model name of the CPU without trademark symbols and the
+    /// clock speed, as cleaned up by `extra::pretty_cpu_model_name()`.
+    ///
+    /// _e.g._ `Intel Core i5-8265U`
+    fn cpu_model_name_pretty(&self) -> Result<String, ReadoutError> {
+        Ok(crate::extra::pretty_cpu_model_name(&self.cpu_model_name()?))
     }
 
-    /// This function should return the average CPU usage over the last minute.
+    /// This function should return the sorted feature flags of the host's processor, which
+    /// tell what instruction set extensions it supports.
+    ///
+    /// _e.g._ `["aes", "avx2", "sse4_2"]` or `["aes", "asimd", "crc32"]` on ARM
+    fn cpu_features(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the vendor of the host's processor.
+    ///
+    /// _e.g._ `GenuineIntel`, `AuthenticAMD` or `ARM`
+    fn cpu_vendor(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the CPU usage in percent. Implementations that can sample
+    /// the CPU times measure it over an interval of 200 milliseconds, others estimate it from
+    /// the load average of the last minute.
     fn cpu_usage(&self) -> Result<usize, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        self.cpu_usage_interval(Duration::from_millis(200))
+    }
+
+    /// This function should return the CPU usage in percent, sampled over the given
+    /// `interval`. \
+    /// Longer intervals give a smoother reading, at the cost of blocking for longer.
+    fn cpu_usage_interval(&self, interval: Duration) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the CPU usage in percent since the previous call, by
+    /// diffing against a snapshot of the CPU times stored in the readout.
+    ///
+    /// The first call has nothing to compare against, so it records a snapshot and
+    /// returns the estimate of `cpu_usage()`; an accurate value requires at least two calls.
+    fn cpu_usage_sampled(&mut self) -> Result<usize, ReadoutError> {
+        self.cpu_usage()
+    }
+
+    /// This function should return the 1, 5 and 15 minute load averages of the system.
+    ///
+    /// _e.g._ `(0.52, 0.58, 0.59)`
+    fn load_average(&self) -> Result<(f64, f64, f64), ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the CPU usage of each logical core in percent, in core
+    /// order, sampled over a short interval of 100 milliseconds.
+    fn cpu_usage_per_core(&self) -> Result<Vec<usize>, ReadoutError> {
+        self.cpu_usage_per_core_interval(Duration::from_millis(100))
+    }
+
+    /// This function should return the CPU usage of each logical core in percent, in core
+    /// order, sampled over the given `interval`. \
+    /// Longer intervals yield more accurate values, at the cost of blocking for longer.
+    fn cpu_usage_per_core_interval(&self, interval: Duration) -> Result<Vec<usize>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the number of physical cores of the host's processor.
     fn cpu_physical_cores(&self) -> Result<usize, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the number of logical cores of the host's processor.
     fn cpu_cores(&self) -> Result<usize, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the logical cores of the host's processor grouped by
+    /// their maximum frequency, as `(max_freq_khz, count)` pairs from the fastest to the
+    /// slowest cluster. Processors whose cores all run at the same speed yield a single group.
+    ///
+    /// _e.g._ `[(2400000, 4), (1800000, 4)]` on a big.LITTLE SoC
+    fn cpu_cores_by_cluster(&self) -> Result<Vec<(u64, usize)>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the temperature of the host's processor in degrees Celsius.
+    ///
+    /// _e.g._ `45.5`
+    fn cpu_temperature(&self) -> Result<f32, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the current clock speed of the host's processor in MHz. \
+    /// On processors whose cores are clocked differently, _e.g._ ARM big.LITTLE designs,
+    /// this is the clock speed of the first core.
+    ///
+    /// _e.g._ `3400`
+    fn cpu_frequency(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the uptime of the OS in seconds.
     fn uptime(&self) -> Result<usize, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the uptime of the OS as a `Duration`.
+    ///
+    /// The default implementation is based on `uptime()`.
+    fn uptime_duration(&self) -> Result<Duration, ReadoutError> {
+        Ok(Duration::from_secs(self.uptime()? as u64))
+    }
+
+    /// This function should return the time at which the OS was booted, in seconds since the
+    /// UNIX epoch.
+    ///
+    /// _e.g._ `1696920343`
+    fn boot_time(&self) -> Result<u64, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the name of the physical machine.
     ///
     /// _e.g._ `MacBookPro11,5`
     fn machine(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the model name of the host, built from the
+    /// firmware-provided vendor, product and version.
+    ///
+    /// _e.g._ `LENOVO ThinkPad X1 Carbon Gen 9 (20XW0055US)`
+    fn host_model(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the name of the OS in a pretty format.
     ///
     /// _e.g._ `macOS 11.2.2 Big Sur`
     fn os_name(&self) -> Result<String, ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
     }
 
     /// This function should return the used disk space in a human-readable and desirable format.
     ///
     /// _e.g._ '1.2TB / 2TB'
     fn disk_space(&self) -> Result<(AdjustedByte, AdjustedByte), ReadoutError> {
-        Err(STANDARD_NO_IMPL.clone())
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the locale of the current user.
+    ///
+    /// _e.g._ `en_US.UTF-8`
+    fn locale(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the configured timezone.
+    ///
+    /// _e.g._ `Europe/Berlin`
+    fn timezone(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the number of running processes.
+    ///
+    /// _e.g._ `312`
+    fn process_count(&self) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the sorted names of the loaded kernel modules.
+    ///
+    /// _e.g._ `["btusb", "nvme", "snd_hda_intel"]`
+    fn loaded_modules(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the GTK theme in use.
+    ///
+    /// _e.g._ `Adwaita-dark`
+    fn theme(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the icon theme in use.
+    ///
+    /// _e.g._ `Papirus`
+    fn icons(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the accent color picked by the user, as RGB.
+    ///
+    /// _e.g._ `(53, 132, 228)`
+    fn accent_color(&self) -> Result<(u8, u8, u8), ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the utilisation of the host's GPU in percent.
+    ///
+    /// _e.g._ `27`
+    fn gpu_usage(&self) -> Result<usize, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the kernel driver bound to every GPU, in the
+    /// same order as `GpuReadout::model()`. GPUs without a bound driver are reported as an
+    /// empty string.
+    ///
+    /// _e.g._ `["i915", "nvidia"]`
+    fn gpu_driver(&self) -> Result<Vec<String>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the temperature of every GPU in degrees Celsius, in the
+    /// same order as `GpuReadout::model()`. GPUs whose temperature can't be read are reported
+    /// as `ReadoutError::MetricNotAvailable`, and a host without a GPU yields an empty `Vec`.
+    ///
+    /// _e.g._ `[Err(MetricNotAvailable), Ok(54.0)]`
+    fn gpu_temperature(&self) -> Result<Vec<Result<f32, ReadoutError>>, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the container or virtual machine the OS is running in,
+    /// using the identifiers of `systemd-detect-virt`, or `none` on bare metal.
+    ///
+    /// _e.g._ `kvm`, `docker`, `lxc`, `wsl`
+    fn virtualization(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+
+    /// This function should return the name of the sound server in use, or of the
+    /// kernel's sound subsystem if no sound server is running.
+    ///
+    /// _e.g._ `PipeWire`, `PulseAudio`, `ALSA`
+    fn audio_server(&self) -> Result<String, ReadoutError> {
+        Err(ReadoutError::NotImplemented)
+    }
+}
+
+/// Holds a snapshot of the battery's state, as returned by `BatteryReadout::all()`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatteryInfo {
+    /// The current battery percentage, in the range of `0` to `100`.
+    pub percentage: u8,
+    /// The current charging state of the battery.
+    pub state: BatteryState,
+    /// The battery's health in percentage, if it could be determined.
+    pub health: Option<u8>,
+}
+
+/// The units the values of a `MemoryReadout` can be converted to, see `MemoryReadout::total_in()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MemoryUnit {
+    Bytes,
+    Kib,
+    Mib,
+    Gib,
+}
+
+impl MemoryUnit {
+    /// Converts an amount of kibibytes, as returned by `MemoryReadout`, into this unit.
+    /// Conversions to larger units round down.
+    ///
+    /// ```
+    /// use libmacchina::traits::MemoryUnit;
+    ///
+    /// assert_eq!(MemoryUnit::Gib.from_kib(16_384_000), 15);
+    /// assert_eq!(MemoryUnit::Bytes.from_kib(2), 2048);
+    /// ```
+    pub fn from_kib(self, kib: u64) -> u64 {
+        match self {
+            MemoryUnit::Bytes => kib.saturating_mul(1024),
+            MemoryUnit::Kib => kib,
+            MemoryUnit::Mib => kib / 1024,
+            MemoryUnit::Gib => kib / (1024 * 1024),
+        }
     }
 }
 
 /// Holds the possible variants for battery status.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BatteryState {
     Charging,
     Discharging,
+    /// The battery is fully charged.
+    Full,
+    /// The device is plugged in, but the battery is neither charging nor discharging.
+    NotCharging,
 }
 
 impl From<BatteryState> for &'static str {
@@ -498,6 +1208,8 @@ impl From<BatteryState> for &'static str {
         match state {
             BatteryState::Charging => "Charging",
             BatteryState::Discharging => "Discharging",
+            BatteryState::Full => "Full",
+            BatteryState::NotCharging => "Not charging",
         }
     }
 }
@@ -505,6 +1217,7 @@ impl From<BatteryState> for &'static str {
 /// The currently running shell is a program, whose path
 /// can be _relative_, or _absolute_.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShellFormat {
     Relative,
     Absolute,
@@ -513,12 +1226,15 @@ pub enum ShellFormat {
 #[derive(Debug)]
 /// There are two distinct kinds of shells, a so called *"current"* shell, i.e. the shell the user is currently using.
 /// And a default shell, i.e. that the user sets for themselves using the `chsh` tool.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShellKind {
     Current,
     Default,
 }
 
 /// The supported package managers whose packages can be extracted.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PackageManager {
     Homebrew,
     MacPorts,
@@ -536,6 +1252,12 @@ pub enum PackageManager {
     Snap,
     Android,
     Pkg,
+    Pip,
+    Nix,
+    Scoop,
+    Chocolatey,
+    Winget,
+    AppImage,
 }
 
 impl ToString for PackageManager {
@@ -557,6 +1279,71 @@ impl ToString for PackageManager {
             PackageManager::Snap => "snap",
             PackageManager::Android => "Android",
             PackageManager::Pkg => "pkg",
+            PackageManager::Pip => "pip",
+            PackageManager::Nix => "nix",
+            PackageManager::Scoop => "scoop",
+            PackageManager::Chocolatey => "chocolatey",
+            PackageManager::Winget => "winget",
+            PackageManager::AppImage => "appimage",
         })
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip<T>(value: T)
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let json = serde_json::to_string(&value).unwrap();
+        let deserialized: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+    }
+
+    #[test]
+    fn test_serde_battery_state() {
+        assert_round_trip(BatteryState::Charging);
+        assert_round_trip(BatteryState::Discharging);
+        assert_round_trip(BatteryState::Full);
+        assert_round_trip(BatteryState::NotCharging);
+    }
+
+    #[test]
+    fn test_serde_memory_unit() {
+        assert_round_trip(MemoryUnit::Bytes);
+        assert_round_trip(MemoryUnit::Gib);
+    }
+
+    #[test]
+    fn test_serde_package_manager() {
+        assert_round_trip(PackageManager::Homebrew);
+        assert_round_trip(PackageManager::Flatpak);
+    }
+
+    #[test]
+    fn test_serde_shell_format() {
+        assert_round_trip(ShellFormat::Relative);
+        assert_round_trip(ShellFormat::Absolute);
+    }
+
+    #[test]
+    fn test_serde_shell_kind() {
+        assert_round_trip(ShellKind::Current);
+        assert_round_trip(ShellKind::Default);
+    }
+
+    #[test]
+    fn test_serde_readout_error() {
+        assert_round_trip(ReadoutError::MetricNotAvailable);
+        assert_round_trip(ReadoutError::Other(String::from("Something went wrong.")));
+        assert_round_trip(ReadoutError::Warning(String::from("Be careful.")));
+        assert_round_trip(ReadoutError::NotImplemented);
+        assert_round_trip(ReadoutError::Timeout);
+        assert_round_trip(ReadoutError::FileRead {
+            path: PathBuf::from("/sys/class/power_supply/BAT0/capacity"),
+            source: std::io::Error::new(std::io::ErrorKind::PermissionDenied, "Permission denied"),
+        });
+    }
+}
@@ -1,5 +1,7 @@
 use crate::extra;
 use crate::traits::*;
+use std::path::PathBuf;
+use std::process::Command;
 use winreg::enums::*;
 use winreg::RegKey;
 
@@ -324,16 +326,28 @@ impl PackageReadout for WindowsPackageReadout {
 
     /// Returns the __number of installed packages__ for the following package managers:
     /// - cargo
+    /// - scoop
+    /// - chocolatey
+    /// - winget
     fn count_pkgs(&self) -> Vec<(PackageManager, usize)> {
-        let mut packages = Vec::new();
+        let mut counters: Vec<crate::shared::PackageCounter> = Vec::new();
         if extra::which("cargo") {
-            match WindowsPackageReadout::count_cargo() {
-                Some(c) => packages.push((PackageManager::Cargo, c)),
-                _ => (),
-            }
+            counters.push((PackageManager::Cargo, WindowsPackageReadout::count_cargo));
+        }
+        if WindowsPackageReadout::scoop_apps().is_some() {
+            counters.push((PackageManager::Scoop, WindowsPackageReadout::count_scoop));
+        }
+        if WindowsPackageReadout::chocolatey_lib().is_some() {
+            counters.push((
+                PackageManager::Chocolatey,
+                WindowsPackageReadout::count_chocolatey,
+            ));
+        }
+        if extra::which("winget.exe") {
+            counters.push((PackageManager::Winget, WindowsPackageReadout::count_winget));
         }
 
-        packages
+        crate::shared::count_packages(counters)
     }
 }
 
@@ -341,6 +355,116 @@ impl WindowsPackageReadout {
     fn count_cargo() -> Option<usize> {
         crate::shared::count_cargo()
     }
+
+    /// Returns the `apps` directory of scoop, which is either in `%SCOOP%`
+    /// or in the user's profile.
+    fn scoop_apps() -> Option<PathBuf> {
+        let root = match std::env::var_os("SCOOP") {
+            Some(root) => PathBuf::from(root),
+            None => PathBuf::from(std::env::var_os("USERPROFILE")?).join("scoop"),
+        };
+
+        let apps = root.join("apps");
+        if apps.is_dir() {
+            Some(apps)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of installed packages for systems
+    /// that have `scoop` installed.
+    ///
+    /// Every app is a directory in `apps`, including scoop itself.
+    fn count_scoop() -> Option<usize> {
+        let apps = extra::list_dir_entries(&WindowsPackageReadout::scoop_apps()?)
+            .iter()
+            .filter(|app| app.is_dir() && app.file_name() != Some(std::ffi::OsStr::new("scoop")))
+            .count();
+
+        if apps != 0 {
+            return Some(apps);
+        }
+
+        None
+    }
+
+    /// Returns the `lib` directory of chocolatey, which is found in `%ChocolateyInstall%`.
+    fn chocolatey_lib() -> Option<PathBuf> {
+        let lib = PathBuf::from(std::env::var_os("ChocolateyInstall")?).join("lib");
+        if lib.is_dir() {
+            Some(lib)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of installed packages for systems
+    /// that have `chocolatey` installed.
+    fn count_chocolatey() -> Option<usize> {
+        let packages = extra::list_dir_entries(&WindowsPackageReadout::chocolatey_lib()?)
+            .iter()
+            .filter(|package| package.is_dir())
+            .count();
+
+        if packages != 0 {
+            return Some(packages);
+        }
+
+        None
+    }
+
+    /// Returns the number of installed packages for systems
+    /// that have `winget` installed.
+    ///
+    /// `winget list` prints a table whose header is underlined by a line of dashes,
+    /// followed by one row per package.
+    fn count_winget() -> Option<usize> {
+        let output = crate::shared::run_with_timeout(
+            Command::new("winget")
+                .arg("list")
+                .arg("--accept-source-agreements")
+                .arg("--disable-interactivity"),
+            crate::shared::COMMAND_TIMEOUT,
+        )?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        // The progress spinner is drawn using carriage returns.
+        let mut lines = stdout
+            .lines()
+            .map(|line| line.rsplit('\r').next().unwrap_or(line).trim());
+        lines.find(|line| !line.is_empty() && line.chars().all(|c| c == '-'))?;
+
+        extra::count_lines(lines.collect::<Vec<_>>().join("\n"))
+    }
+}
+
+pub struct WindowsGpuReadout;
+
+impl GpuReadout for WindowsGpuReadout {
+    fn new() -> Self {
+        WindowsGpuReadout {}
+    }
+}
+
+pub struct WindowsDiskReadout;
+
+impl DiskReadout for WindowsDiskReadout {
+    fn new() -> Self {
+        WindowsDiskReadout {}
+    }
+}
+
+pub struct WindowsNetworkReadout;
+
+impl NetworkReadout for WindowsNetworkReadout {
+    fn new() -> Self {
+        WindowsNetworkReadout {}
+    }
 }
 
 struct WindowsVersionInfo {
@@ -48,6 +48,40 @@ pub fn is_running_qtile() -> bool {
     false
 }
 
+#[cfg(target_os = "linux")]
+/// Detects a known Wayland compositor by walking `/proc` for its process name.
+pub fn detect_wayland_compositor_process() -> Option<String> {
+    const COMPOSITORS: [(&str, &str); 12] = [
+        ("sway", "Sway"),
+        ("Hyprland", "Hyprland"),
+        ("wayfire", "Wayfire"),
+        ("river", "River"),
+        ("niri", "Niri"),
+        ("labwc", "Labwc"),
+        ("hikari", "Hikari"),
+        ("weston", "Weston"),
+        ("kwin_wayland", "KWin"),
+        ("gnome-shell", "Mutter"),
+        ("cosmic-comp", "COSMIC"),
+        ("phoc", "Phoc"),
+    ];
+
+    let entries = std::fs::read_dir("/proc").ok()?;
+    for entry in entries.flatten() {
+        let comm = match std::fs::read_to_string(entry.path().join("comm")) {
+            Ok(comm) => comm,
+            Err(_) => continue,
+        };
+
+        let comm = comm.trim();
+        if let Some((_, name)) = COMPOSITORS.iter().find(|(process, _)| *process == comm) {
+            return Some(String::from(*name));
+        }
+    }
+
+    None
+}
+
 #[cfg(target_os = "linux")]
 pub fn detect_wayland_window_manager() -> Result<String, ReadoutError> {
     if is_running_sway() {
@@ -56,12 +90,77 @@ pub fn detect_wayland_window_manager() -> Result<String, ReadoutError> {
         Ok(String::from("Qtile"))
     } else if is_running_wayfire() {
         Ok(String::from("Wayfire"))
+    } else if let Some(compositor) = detect_wayland_compositor_process() {
+        Ok(compositor)
+    } else if let Ok(desktop) = std::env::var("XDG_CURRENT_DESKTOP") {
+        match desktop.split(':').next() {
+            Some(name) if !name.is_empty() => Ok(extra::ucfirst(name)),
+            _ => Err(ReadoutError::Other(String::from("Unknown window manager."))),
+        }
     } else {
         Err(ReadoutError::Other(String::from("Unknown window manager.")))
     }
 }
 
+/// Reads the name of the window manager through the EWMH
+/// `_NET_SUPPORTING_WM_CHECK` and `_NET_WM_NAME` properties using `xprop`.
+///
+/// Returns `ReadoutError::NotImplemented` if no display connection can be
+/// established, _i.e._ `DISPLAY` is unset or `xprop` isn't installed, and
+/// `ReadoutError::MetricNotAvailable` if the window manager does not set these
+/// properties.
+pub fn detect_ewmh_window_manager() -> Result<String, ReadoutError> {
+    if std::env::var_os("DISPLAY").is_none() || !extra::which("xprop") {
+        return Err(ReadoutError::NotImplemented);
+    }
+
+    let xprop = |args: &[&str]| -> Option<String> {
+        let output = crate::shared::run_with_timeout(
+            Command::new("xprop").args(args),
+            crate::shared::COMMAND_TIMEOUT,
+        )?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        String::from_utf8(output.stdout).ok()
+    };
+
+    // e.g. "_NET_SUPPORTING_WM_CHECK: window id # 0x1000001"
+    let check = xprop(&["-root", "-notype", "_NET_SUPPORTING_WM_CHECK"])
+        .ok_or(ReadoutError::MetricNotAvailable)?;
+    let window_id = check
+        .rsplit('#')
+        .next()
+        .map(str::trim)
+        .filter(|id| id.starts_with("0x"))
+        .ok_or(ReadoutError::MetricNotAvailable)?;
+
+    // e.g. "_NET_WM_NAME = "i3""
+    let name = xprop(&["-id", window_id, "-notype", "_NET_WM_NAME"])
+        .ok_or(ReadoutError::MetricNotAvailable)?;
+    let name = name
+        .split_once('=')
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+        .ok_or(ReadoutError::MetricNotAvailable)?;
+
+    Ok(name)
+}
+
+/// Detects the X11 window manager through EWMH, falling back to `wmctrl`.
+///
+/// Returns `ReadoutError::NotImplemented` if `DISPLAY` is unset.
 pub fn detect_xorg_window_manager() -> Result<String, ReadoutError> {
+    if let Ok(winman_name) = detect_ewmh_window_manager() {
+        return Ok(winman_name);
+    }
+
+    if std::env::var_os("DISPLAY").is_none() {
+        return Err(ReadoutError::NotImplemented);
+    }
+
     if extra::which("wmctrl") {
         let wmctrl = Command::new("wmctrl")
             .arg("-m")
@@ -101,6 +200,6 @@ pub fn detect_xorg_window_manager() -> Result<String, ReadoutError> {
     }
 
     Err(ReadoutError::Other(
-        "\"wmctrl\" must be installed to display your window manager.".to_string(),
+        "\"xprop\" or \"wmctrl\" must be installed to display your window manager.".to_string(),
     ))
 }